@@ -0,0 +1,230 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Implementation of `#[derive(IntoStatic)]`
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_quote, spanned::Spanned, visit_mut::VisitMut, Data, DeriveInput, Error, Fields,
+    GenericParam, Generics, Ident, Lifetime, Path, Type, TypePath, WherePredicate,
+};
+
+/// Generate the `IntoStatic` impl for a struct or enum
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let subst = Substitute::new(&input.generics);
+
+    let cases: Vec<(Path, &Fields)> = match &input.data {
+        Data::Struct(data) => vec![(parse_quote!(#name), &data.fields)],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|v| {
+                let variant = &v.ident;
+                (parse_quote!(#name::#variant), &v.fields)
+            })
+            .collect(),
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "`IntoStatic` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let mut errors = None::<Error>;
+    for (_, fields) in &cases {
+        for field in fields.iter() {
+            if let Err(e) = subst.check_field_type(&field.ty) {
+                match &mut errors {
+                    Some(errors) => errors.combine(e),
+                    None => errors = Some(e),
+                }
+            }
+        }
+    }
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let mut generics = input.generics.clone();
+    let predicates = subst.predicates(&input.generics);
+    generics.make_where_clause().predicates.extend(predicates);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut static_ty: Type = parse_quote!(#name #ty_generics);
+    subst.clone().visit_type_mut(&mut static_ty);
+
+    let arms = cases
+        .iter()
+        .map(|(path, fields)| convert_case(path, fields));
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::eso::into_static::IntoStatic for #name #ty_generics
+        #where_clause
+        {
+            type Static = #static_ty;
+
+            fn into_static(self) -> Self::Static {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Generate the match arm that converts one struct or enum variant
+fn convert_case(path: &Path, fields: &Fields) -> TokenStream {
+    let bindings: Vec<Ident> = (0..fields.len())
+        .map(|i| format_ident!("__eso_field_{}", i))
+        .collect();
+    let converted = fields.iter().zip(&bindings).map(|(field, binding)| {
+        quote_spanned! {field.ty.span()=>
+            ::eso::into_static::IntoStatic::into_static(#binding)
+        }
+    });
+    match fields {
+        Fields::Named(named) => {
+            let names: Vec<&Ident> = named
+                .named
+                .iter()
+                .filter_map(|f| f.ident.as_ref())
+                .collect();
+            quote! {
+                #path { #(#names: #bindings),* } => #path { #(#names: #converted),* },
+            }
+        }
+        Fields::Unnamed(_) => quote! {
+            #path(#(#bindings),*) => #path(#(#converted),*),
+        },
+        Fields::Unit => quote! {
+            #path => #path,
+        },
+    }
+}
+
+/// Rewrites types from the generic form of the item to
+/// its `'static` form: lifetime parameters become `'static` and
+/// type parameters `T` become `<T as IntoStatic>::Static`.
+#[derive(Clone)]
+struct Substitute {
+    lifetimes: HashSet<Ident>,
+    types: HashSet<Ident>,
+}
+
+impl Substitute {
+    fn new(generics: &Generics) -> Self {
+        let mut lifetimes = HashSet::new();
+        let mut types = HashSet::new();
+        for param in &generics.params {
+            match param {
+                GenericParam::Lifetime(l) => {
+                    lifetimes.insert(l.lifetime.ident.clone());
+                }
+                GenericParam::Type(t) => {
+                    types.insert(t.ident.clone());
+                }
+                GenericParam::Const(_) => {}
+            }
+        }
+        Substitute { lifetimes, types }
+    }
+
+    fn apply<T: Clone>(&self, node: &T, visit: fn(&mut Self, &mut T)) -> T {
+        let mut node = node.clone();
+        visit(&mut self.clone(), &mut node);
+        node
+    }
+
+    /// Reject field types that cannot possibly be converted, so that
+    /// the user gets a more helpful message than an unsatisfied bound.
+    ///
+    /// Only a reference that is the field type itself, or an element
+    /// of a tuple or array field, is rejected. References inside the
+    /// generic arguments of a path, e.g. the `E` type of an `Eso`, are
+    /// left to the type checker, which reports them at the field.
+    fn check_field_type(&self, ty: &Type) -> syn::Result<()> {
+        match ty {
+            Type::Reference(r) => {
+                let borrows_param = match &r.lifetime {
+                    Some(l) => self.lifetimes.contains(&l.ident),
+                    None => true,
+                };
+                if borrows_param {
+                    Err(Error::new_spanned(
+                        r,
+                        "`IntoStatic` cannot convert a borrowed reference into a `'static` one, \
+                         use an `Eso` or a `Cow` instead",
+                    ))
+                } else {
+                    Ok(())
+                }
+            }
+            Type::Tuple(t) => t.elems.iter().try_for_each(|e| self.check_field_type(e)),
+            Type::Array(a) => self.check_field_type(&a.elem),
+            Type::Paren(p) => self.check_field_type(&p.elem),
+            Type::Group(g) => self.check_field_type(&g.elem),
+            _ => Ok(()),
+        }
+    }
+
+    /// The additional where clause predicates of the derived impl.
+    ///
+    /// There are no predicates on the field types, since those would
+    /// send the trait solver into an endless loop for recursive types.
+    /// Fields that cannot be converted make the generated function body
+    /// fail to type check at the field instead.
+    fn predicates(&self, generics: &Generics) -> Vec<WherePredicate> {
+        let mut predicates = Vec::new();
+
+        for param in generics.type_params() {
+            let ident = &param.ident;
+            predicates.push(parse_quote!(#ident: ::eso::into_static::IntoStatic));
+            if !param.bounds.is_empty() {
+                let bounds = param
+                    .bounds
+                    .iter()
+                    .map(|b| self.apply(b, Self::visit_type_param_bound_mut));
+                predicates.push(parse_quote! {
+                    <#ident as ::eso::into_static::IntoStatic>::Static: #(#bounds)+*
+                });
+            }
+        }
+
+        if let Some(where_clause) = &generics.where_clause {
+            for predicate in &where_clause.predicates {
+                predicates.push(self.apply(predicate, Self::visit_where_predicate_mut));
+            }
+        }
+
+        predicates
+    }
+}
+
+impl VisitMut for Substitute {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if self.lifetimes.contains(&lifetime.ident) {
+            *lifetime = Lifetime::new("'static", lifetime.span());
+        }
+    }
+
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            if let Some(ident) = path.get_ident() {
+                if self.types.contains(ident) {
+                    *ty = parse_quote!(<#ident as ::eso::into_static::IntoStatic>::Static);
+                    return;
+                }
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
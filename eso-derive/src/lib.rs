@@ -0,0 +1,230 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Derive macros for the [`eso`](https://docs.rs/eso) crate.
+//!
+//! Use them through the `derive` feature of `eso`, which re-exports
+//! them next to the traits they implement:
+//!
+//! ```toml
+//! [dependencies.eso]
+//! version = "0.0.3-active.*"
+//! features = ["derive"]
+//! ```
+
+#![deny(missing_docs, unused_qualifications)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod into_static;
+mod newtype;
+
+/// Derive `eso::into_static::IntoStatic` for a struct or enum.
+///
+/// The `'static` form of the type replaces every lifetime parameter by
+/// `'static` and every type parameter `T` by
+/// `<T as IntoStatic>::Static`. Each field is converted with its own
+/// [`IntoStatic`](https://docs.rs/eso/*/eso/into_static/trait.IntoStatic.html)
+/// implementation, so fields may be [`Eso`]s, standard library
+/// containers of them, or other types deriving `IntoStatic`:
+///
+/// ```
+/// use eso::{into_static::IntoStatic, shorthand::t};
+///
+/// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+///
+/// #[derive(Debug, IntoStatic)]
+/// enum Expr<'src, 'name, T> {
+///     Literal(T),
+///     Var { name: Str<'name> },
+///     Call(Str<'name>, Vec<Expr<'src, 'name, T>>, Option<Str<'src>>),
+///     Unit,
+/// }
+///
+/// fn parse<'a>(input: &'a str) -> Expr<'a, 'a, i32> {
+///     let args = input.split(',').map(|n| Expr::Var { name: Str::from_ref(n) });
+///     Expr::Call(Str::from_static("f"), args.collect(), Some(Str::from_ref(input)))
+/// }
+///
+/// let input = String::from("x,y");
+/// let expr: Expr<'static, 'static, i32> = parse(&input).into_static();
+/// drop(input);
+/// match expr {
+///     Expr::Call(f, args, _) => {
+///         assert!(f.is_static());
+///         assert_eq!(args.len(), 2);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// The [`Eso`] can also be written out in the field type, with the
+/// borrowed reference as its `E` type:
+///
+/// ```
+/// use eso::{into_static::IntoStatic, shorthand::t};
+///
+/// #[derive(IntoStatic)]
+/// struct Inline<'a> {
+///     name: t::ESO<&'a str, &'static str, String>,
+///     aliases: Vec<t::ESO<&'a str, &'static str, String>>,
+/// }
+///
+/// let input = String::from("Hello");
+/// let inline = Inline { name: t::ESO::from_ref(&input), aliases: Vec::new() };
+/// let inline: Inline<'static> = inline.into_static();
+/// drop(input);
+/// assert!(inline.name.is_owning());
+/// ```
+///
+/// Fields holding plain borrowed references cannot be made `'static`
+/// and are rejected:
+///
+/// ```compile_fail
+/// # use eso::into_static::IntoStatic;
+/// #[derive(IntoStatic)]
+/// struct Borrowing<'a> {
+///     name: &'a str, // error: use an `Eso` or a `Cow` instead
+/// }
+/// ```
+///
+/// [`Eso`]: https://docs.rs/eso/*/eso/eso/enum.Eso.html
+#[proc_macro_derive(IntoStatic)]
+pub fn derive_into_static(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_static::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive the usual boilerplate for a newtype around an `Eso`.
+///
+/// The struct must be a tuple struct with a single field, which is
+/// an [`Eso`] or a type alias of one:
+///
+/// ```
+/// use eso::{shorthand::t, EsoNewtype};
+///
+/// #[derive(Debug, Clone, EsoNewtype)]
+/// #[eso(rename(from_ref = "from_str", from_owned = "from_string"), borrow, take)]
+/// pub struct WrappedString<'a>(t::ESO<&'a str, &'static str, String>);
+///
+/// let a = WrappedString::from_str("Hello World");
+/// let b = WrappedString::from_static("Hello World");
+/// let c: WrappedString = String::from("Hello World").into();
+/// assert_eq!(&*a, &*b);
+/// assert_eq!(&*b, &*c);
+///
+/// let c: WrappedString<'static> = a.narrow().into_owning();
+/// assert_eq!(c.len(), 11);
+/// ```
+///
+/// By default, the following are generated, with the inner types
+/// `E`, `S` and `O` of the [`Eso`]:
+///
+/// | Key           | Generates                                                  |
+/// |---------------|------------------------------------------------------------|
+/// | `from_ref`    | `const fn from_ref(e: E) -> Self`                          |
+/// | `from_static` | `const fn from_static(s: S) -> Self`                       |
+/// | `from_owned`  | `const fn from_owned(o: O) -> Self`                        |
+/// | `from_cow`    | `fn from_cow(cow: Cow<T>) -> Self`                         |
+/// | `narrow`      | `fn narrow<'b>(self) -> X<'b>`, if `X` has lifetimes       |
+/// | `into_static` | `fn into_static(self) -> X<'static>`                       |
+/// | `into_owning` | `fn into_owning(self) -> X<'static>`                       |
+/// | `deref`       | `impl Deref for X`, with the target of `E` as its target   |
+/// | `from`        | `impl From<E> for X` and `impl From<O> for X`              |
+///
+/// Items that do not fit the shape of the field are left out by
+/// default, like `from_ref` for a field that cannot be ephemeral:
+///
+/// ```
+/// use eso::{shorthand::t, EsoNewtype};
+/// use std::borrow::Cow;
+///
+/// #[derive(EsoNewtype)]
+/// pub struct Lasting<'a>(t::SO<&'a str, &'static str, String>);
+///
+/// #[derive(EsoNewtype)]
+/// pub struct Fresh<'a>(t::EO<&'a str, &'static str, String>);
+///
+/// let a = Lasting::from_static("Hello");
+/// let b: Lasting = String::from("Hello").into();
+/// assert_eq!(&*a, &*b);
+///
+/// let c = Fresh::from_cow(Cow::Borrowed("Hello"));
+/// let d: Fresh<'static> = Fresh::from_ref("Hello").into_static();
+/// assert_eq!(&*c, &*d);
+/// ```
+///
+/// If the field is a type alias, its shape cannot be read. The items
+/// are then generated with `where` clauses that only hold if the
+/// parts they need are present, so using one that does not fit is
+/// an error:
+///
+/// ```compile_fail
+/// # use eso::{shorthand::t, EsoNewtype};
+/// type Str<'a> = t::SO<&'a str, &'static str, String>;
+///
+/// #[derive(EsoNewtype)]
+/// pub struct Lasting<'a>(Str<'a>);
+///
+/// let a = Lasting::from_static("Hello");
+/// let b = Lasting::from_ref("Hello"); // error: `E` is absent
+/// ```
+///
+/// Additionally, these can be requested:
+///
+/// | Key          | Generates                                                   |
+/// |--------------|-------------------------------------------------------------|
+/// | `borrow`     | `impl<'b> Borrow<'b, X<'b>> for X`, borrowing an owned value |
+/// | `take`       | `impl Take<X<'static>> for X`, cloning any reference        |
+///
+/// The `#[eso(...)]` attribute accepts:
+///
+/// - `only(key, ...)`: generate only the given items
+/// - `skip(key, ...)`: do not generate the given items
+/// - `rename(key = "name", ...)`: rename the generated methods
+/// - `deref = "Type"`: the [`Deref`](std::ops::Deref) target
+/// - `borrow = "Type<'_>"`: generate [`Borrow`] into another newtype,
+///   where `'_` stands for the lifetime of the borrow
+/// - `take = "Type"`: generate [`Take`] into another newtype
+/// - `bound = "T: Clone, ..."`: additional `where` clauses for all
+///   generated items
+///
+/// For example, the types of a reference/owner pair of newtypes:
+///
+/// ```
+/// use eso::{shorthand::t, EsoNewtype};
+///
+/// #[derive(EsoNewtype)]
+/// #[eso(only(from_owned, deref), borrow = "StringRef<'_>")]
+/// pub struct OwnedString(t::O<&'static str, &'static str, String>);
+///
+/// #[derive(EsoNewtype)]
+/// #[eso(only(from_ref, from_static, deref), take = "OwnedString")]
+/// pub struct StringRef<'a>(t::ES<&'a str, &'static str, String>);
+///
+/// use eso::borrow::{Borrow, Take};
+/// let owned = OwnedString::from_owned("Hello".to_string());
+/// let borrowed: StringRef = owned.borrow();
+/// assert_eq!(&*borrowed, "Hello");
+/// let owned: OwnedString = borrowed.to_owned();
+/// assert_eq!(owned.len(), 5);
+/// ```
+///
+/// [`Eso`]: https://docs.rs/eso/*/eso/eso/enum.Eso.html
+/// [`Borrow`]: https://docs.rs/eso/*/eso/borrow/trait.Borrow.html
+/// [`Take`]: https://docs.rs/eso/*/eso/borrow/trait.Take.html
+#[proc_macro_derive(EsoNewtype, attributes(eso))]
+pub fn derive_eso_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    newtype::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,660 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Implementation of `#[derive(EsoNewtype)]`
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    meta::ParseNestedMeta, parse_quote, punctuated::Punctuated, visit_mut::VisitMut, Attribute,
+    Data, DeriveInput, Error, Fields, GenericArgument, GenericParam, Generics, Ident, Lifetime,
+    LitStr, Path, PathArguments, Token, Type, WherePredicate,
+};
+
+/// The things `#[derive(EsoNewtype)]` can generate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Item {
+    FromRef,
+    FromStatic,
+    FromOwned,
+    FromCow,
+    Narrow,
+    IntoStatic,
+    IntoOwning,
+    Deref,
+    From,
+    Borrow,
+    Take,
+}
+
+impl Item {
+    const ALL: [Item; 11] = [
+        Item::FromRef,
+        Item::FromStatic,
+        Item::FromOwned,
+        Item::FromCow,
+        Item::Narrow,
+        Item::IntoStatic,
+        Item::IntoOwning,
+        Item::Deref,
+        Item::From,
+        Item::Borrow,
+        Item::Take,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            Item::FromRef => "from_ref",
+            Item::FromStatic => "from_static",
+            Item::FromOwned => "from_owned",
+            Item::FromCow => "from_cow",
+            Item::Narrow => "narrow",
+            Item::IntoStatic => "into_static",
+            Item::IntoOwning => "into_owning",
+            Item::Deref => "deref",
+            Item::From => "from",
+            Item::Borrow => "borrow",
+            Item::Take => "take",
+        }
+    }
+
+    /// Whether the item is generated without being asked for.
+    ///
+    /// [`Borrow`](Item::Borrow) and [`Take`](Item::Take) usually
+    /// convert between different newtypes, so they are opt-in.
+    fn is_default(self) -> bool {
+        !matches!(self, Item::Borrow | Item::Take)
+    }
+
+    /// Whether the item is a method that can be renamed
+    fn is_method(self) -> bool {
+        !matches!(self, Item::Deref | Item::From | Item::Borrow | Item::Take)
+    }
+
+    fn parse(meta: &ParseNestedMeta) -> syn::Result<Item> {
+        Item::ALL
+            .iter()
+            .copied()
+            .find(|item| meta.path.is_ident(item.key()))
+            .ok_or_else(|| {
+                let keys: Vec<_> = Item::ALL.iter().map(|item| item.key()).collect();
+                meta.error(format!("expected one of: {}", keys.join(", ")))
+            })
+    }
+}
+
+/// The options given in `#[eso(...)]` attributes
+#[derive(Default)]
+struct Options {
+    only: Option<BTreeSet<Item>>,
+    skip: BTreeSet<Item>,
+    extra: BTreeSet<Item>,
+    renames: BTreeMap<Item, Ident>,
+    deref: Option<Type>,
+    borrow: Option<Type>,
+    take: Option<Type>,
+    bounds: Vec<WherePredicate>,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Options> {
+        let mut options = Options::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("eso")) {
+            attr.parse_nested_meta(|meta| options.parse_one(meta))?;
+        }
+        Ok(options)
+    }
+
+    fn parse_one(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("only") || meta.path.is_ident("skip") {
+            let mut items = BTreeSet::new();
+            meta.parse_nested_meta(|item| {
+                items.insert(Item::parse(&item)?);
+                Ok(())
+            })?;
+            if meta.path.is_ident("only") {
+                self.only.get_or_insert_with(BTreeSet::new).extend(items);
+            } else {
+                self.skip.extend(items);
+            }
+        } else if meta.path.is_ident("rename") {
+            meta.parse_nested_meta(|item| {
+                let key = Item::parse(&item)?;
+                if !key.is_method() {
+                    return Err(item.error(format!("`{}` cannot be renamed", key.key())));
+                }
+                let name: LitStr = item.value()?.parse()?;
+                self.renames.insert(key, name.parse()?);
+                Ok(())
+            })?;
+        } else if meta.path.is_ident("deref") {
+            self.deref = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+        } else if meta.path.is_ident("borrow") || meta.path.is_ident("take") {
+            let item = Item::parse(&meta)?;
+            self.extra.insert(item);
+            if meta.input.peek(Token![=]) {
+                let target = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                match item {
+                    Item::Borrow => self.borrow = target,
+                    _ => self.take = target,
+                }
+            }
+        } else if meta.path.is_ident("bound") {
+            let bounds = meta.value()?.parse::<LitStr>()?;
+            self.bounds.extend(
+                bounds.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?,
+            );
+        } else {
+            return Err(meta.error(
+                "expected one of: only(..), skip(..), rename(..), deref = \"..\", \
+                 borrow, take, bound = \"..\"",
+            ));
+        }
+        Ok(())
+    }
+
+    fn items(&self) -> BTreeSet<Item> {
+        let mut items = match &self.only {
+            Some(only) => only.clone(),
+            None => Item::ALL
+                .iter()
+                .copied()
+                .filter(|item| item.is_default())
+                .collect(),
+        };
+        items.retain(|item| !self.skip.contains(item));
+        items.extend(&self.extra);
+        items
+    }
+
+    /// Whether the item was asked for, rather than being generated
+    /// by default
+    fn is_explicit(&self, item: Item) -> bool {
+        self.extra.contains(&item) || self.only.as_ref().is_some_and(|o| o.contains(&item))
+    }
+
+    fn name(&self, item: Item) -> Ident {
+        self.renames
+            .get(&item)
+            .cloned()
+            .unwrap_or_else(|| Ident::new(item.key(), proc_macro2::Span::call_site()))
+    }
+}
+
+/// Generate the newtype boilerplate for a `struct X<'a>(Eso<..>)`
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let field_ty = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => return Err(newtype_error(&input)),
+        },
+        _ => return Err(newtype_error(&input)),
+    };
+    let options = Options::parse(&input.attrs)?;
+    let mut items = options.items();
+
+    let name = &input.ident;
+    let lifetimes: Vec<&Lifetime> = input.generics.lifetimes().map(|l| &l.lifetime).collect();
+    if lifetimes.is_empty() && items.contains(&Item::Narrow) {
+        if options.is_explicit(Item::Narrow) {
+            return Err(Error::new_spanned(
+                name,
+                "`narrow` needs a struct with a lifetime parameter",
+            ));
+        }
+        items.remove(&Item::Narrow);
+    }
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(options.bounds.iter().cloned());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let parts = Parts::new(field_ty);
+    let static_self = with_lifetimes(name, &input.generics, &parse_quote!('static));
+    let borrowed = parse_quote!('__eso_b);
+
+    let mut methods = Vec::new();
+    let mut impls = Vec::new();
+
+    for item in items {
+        let explicit = options.is_explicit(item);
+        if !explicit && !parts.applies(item) {
+            continue;
+        }
+        let method = options.name(item);
+        match item {
+            Item::FromRef | Item::FromStatic | Item::FromOwned => {
+                let (i, what, ctor, letter) = match item {
+                    Item::FromRef => (0, "an ephemeral reference", quote!(from_ref), "E"),
+                    Item::FromStatic => (1, "a static reference", quote!(from_static), "S"),
+                    _ => (2, "an owned value", quote!(from_owned), "O"),
+                };
+                let ty = parts.ty(i);
+                let doc = format!("Create a [`{}`] from {}", name, what);
+                if parts.present(&[i]) {
+                    methods.push(quote! {
+                        #[doc = #doc]
+                        pub const fn #method(x: #ty) -> Self {
+                            Self(::eso::Eso::#ctor(x))
+                        }
+                    });
+                } else {
+                    let alias = parts.alias(letter);
+                    methods.push(quote! {
+                        #[doc = #doc]
+                        pub fn #method(x: #ty) -> Self
+                        where
+                            for<'__eso> #alias: ::eso::maybe::Relax<#field_ty>,
+                        {
+                            Self(::eso::maybe::Relax::relax(<#alias>::#ctor(x)))
+                        }
+                    });
+                }
+            }
+            Item::FromCow => {
+                let (e, o) = (parts.ty(0), parts.ty(2));
+                let (relax, body) = if parts.present(&[0, 2]) {
+                    (quote!(), quote!(::eso::Eso::from_cow(cow)))
+                } else {
+                    let alias = parts.alias("EO");
+                    (
+                        quote!(for<'__eso> #alias: ::eso::maybe::Relax<#field_ty>,),
+                        quote!(::eso::maybe::Relax::relax(<#alias>::from_cow(cow))),
+                    )
+                };
+                let doc = format!(
+                    "Create a [`{}`] from a [`Cow`](std::borrow::Cow), keeping a borrowed value borrowed",
+                    name
+                );
+                methods.push(quote! {
+                    #[doc = #doc]
+                    pub fn #method<'__eso_c, __EsoT>(cow: ::std::borrow::Cow<'__eso_c, __EsoT>) -> Self
+                    where
+                        __EsoT: ?Sized + ::std::borrow::ToOwned,
+                        #e: ::std::convert::From<&'__eso_c __EsoT>,
+                        #o: ::std::convert::From<<__EsoT as ::std::borrow::ToOwned>::Owned>,
+                        #relax
+                    {
+                        Self(#body)
+                    }
+                });
+            }
+            Item::Narrow => {
+                let narrowed = with_lifetimes(name, &input.generics, &borrowed);
+                methods.push(quote! {
+                    /// Shorten the lifetime, without cloning an owned value
+                    pub fn #method<#borrowed>(self) -> #narrowed
+                    where
+                        #(#lifetimes: #borrowed),*
+                    {
+                        self
+                    }
+                });
+            }
+            Item::IntoStatic | Item::IntoOwning => {
+                let (doc, convert, helper, helper_fn) = match item {
+                    Item::IntoStatic => (
+                        "Clone an ephemeral reference, so that the result is\n\
+                         no longer tied to any lifetime",
+                        quote!(into_static),
+                        quote!(IntoStaticAs),
+                        quote!(into_static_as),
+                    ),
+                    _ => (
+                        "Clone any reference, so that the result is definitely owned",
+                        quote!(into_owning),
+                        quote!(IntoOwningAs),
+                        quote!(into_owning_as),
+                    ),
+                };
+                if parts.present(&[2]) {
+                    methods.push(quote! {
+                        #[doc = #doc]
+                        pub fn #method(self) -> #static_self {
+                            #name(self.0.#convert().relax())
+                        }
+                    });
+                } else {
+                    let static_field = with_static(field_ty, &lifetimes);
+                    methods.push(quote! {
+                        #[doc = #doc]
+                        pub fn #method(self) -> #static_self
+                        where
+                            for<'__eso> #field_ty: ::eso::__private::#helper<#static_field>,
+                        {
+                            #name(::eso::__private::#helper::#helper_fn(self.0))
+                        }
+                    });
+                }
+            }
+            Item::Deref => {
+                let target = match &options.deref {
+                    Some(target) => quote!(#target),
+                    None => {
+                        let e = parts.ty(0);
+                        quote!(<#e as ::std::ops::Deref>::Target)
+                    }
+                };
+                impls.push(quote! {
+                    #[automatically_derived]
+                    impl #impl_generics ::std::ops::Deref for #name #ty_generics #where_clause {
+                        type Target = #target;
+
+                        fn deref(&self) -> &Self::Target {
+                            self.0.get_ref()
+                        }
+                    }
+                });
+            }
+            Item::From => {
+                if !parts.applies(Item::From) {
+                    return Err(Error::new_spanned(
+                        field_ty,
+                        "`from` needs the field to be written as `Eso<..>` or as an alias \
+                         from `eso::shorthand::t`, with `E` or `O` present",
+                    ));
+                }
+                let constructors = [quote!(from_ref), quote!(from_static), quote!(from_owned)];
+                for i in [0, 2] {
+                    if let Slot::Present(ty) = &parts.slots[i] {
+                        let constructor = &constructors[i];
+                        impls.push(quote! {
+                            #[automatically_derived]
+                            impl #impl_generics ::std::convert::From<#ty> for #name #ty_generics #where_clause {
+                                fn from(x: #ty) -> Self {
+                                    Self(::eso::Eso::#constructor(x))
+                                }
+                            }
+                        });
+                    }
+                }
+            }
+            Item::Borrow => {
+                let target = match &options.borrow {
+                    Some(target) => replace_elided(target, &borrowed),
+                    None => with_lifetimes(name, &input.generics, &borrowed),
+                };
+                let ctor = constructor(&target)?;
+                let mut generics = generics.clone();
+                generics.params.insert(0, parse_quote!(#borrowed));
+                let predicates = &mut generics.make_where_clause().predicates;
+                predicates.extend(
+                    lifetimes
+                        .iter()
+                        .map(|lt| -> WherePredicate { parse_quote!(#lt: #borrowed) }),
+                );
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                impls.push(quote! {
+                    #[automatically_derived]
+                    impl #impl_generics ::eso::borrow::Borrow<#borrowed, #target> for #name #ty_generics
+                    #where_clause
+                    {
+                        fn borrow(&#borrowed self) -> #target {
+                            #ctor(self.0.reference().relax())
+                        }
+                    }
+                });
+            }
+            Item::Take => {
+                let target = options.take.clone().unwrap_or_else(|| static_self.clone());
+                let ctor = constructor(&target)?;
+                impls.push(quote! {
+                    #[automatically_derived]
+                    impl #impl_generics ::eso::borrow::Take<#target> for #name #ty_generics
+                    #where_clause
+                    {
+                        fn own(self) -> #target {
+                            #ctor(self.0.into_owning().relax())
+                        }
+
+                        fn to_owned(&self) -> #target {
+                            #ctor(self.0.to_owning().relax())
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#methods)*
+        }
+
+        #(#impls)*
+    })
+}
+
+/// What is known about one type parameter of the wrapped [`Eso`]
+enum Slot {
+    /// An `An<T>`, with its inner type
+    Present(Box<Type>),
+    /// A `No<T>`
+    Absent,
+    /// Anything else, like a type parameter or an alias
+    Unknown,
+}
+
+/// What is known about the type parameters of the wrapped [`Eso`]
+struct Parts<'t> {
+    field: &'t Type,
+    slots: [Slot; 3],
+}
+
+impl<'t> Parts<'t> {
+    fn new(field: &'t Type) -> Self {
+        Parts {
+            field,
+            slots: eso_parts(field).unwrap_or([Slot::Unknown, Slot::Unknown, Slot::Unknown]),
+        }
+    }
+
+    /// The inner type of the `i`th type parameter
+    fn ty(&self, i: usize) -> TokenStream {
+        match &self.slots[i] {
+            Slot::Present(ty) => quote!(#ty),
+            Slot::Absent | Slot::Unknown => {
+                let field = self.field;
+                let assoc = [quote!(E), quote!(S), quote!(O)];
+                let assoc = &assoc[i];
+                quote!(<#field as ::eso::eso::EsoTypes>::#assoc)
+            }
+        }
+    }
+
+    /// Whether all of the given type parameters are known to be present
+    fn present(&self, slots: &[usize]) -> bool {
+        slots
+            .iter()
+            .all(|&i| matches!(self.slots[i], Slot::Present(_)))
+    }
+
+    /// The type parameters that must be present for the item to work
+    fn needs(item: Item) -> &'static [usize] {
+        match item {
+            Item::FromRef => &[0],
+            Item::FromStatic => &[1],
+            Item::FromOwned | Item::IntoStatic | Item::IntoOwning => &[2],
+            Item::FromCow => &[0, 2],
+            Item::From | Item::Narrow | Item::Deref | Item::Borrow | Item::Take => &[],
+        }
+    }
+
+    /// Whether the item makes sense for the known type parameters.
+    ///
+    /// Items that need an unknown type parameter are generated with
+    /// `where` clauses that only hold if it is present.
+    fn applies(&self, item: Item) -> bool {
+        if item == Item::From {
+            return self.present(&[0]) || self.present(&[2]);
+        }
+        Parts::needs(item)
+            .iter()
+            .all(|&i| !matches!(self.slots[i], Slot::Absent))
+    }
+
+    /// The alias `t::<letters>` applied to the inner types
+    fn alias(&self, letters: &str) -> TokenStream {
+        let letters = Ident::new(letters, proc_macro2::Span::call_site());
+        let [e, s, o] = [0, 1, 2].map(|i| self.ty(i));
+        quote!(::eso::shorthand::t::#letters<#e, #s, #o>)
+    }
+}
+
+/// Read the type parameters from an `Eso<An<E>, No<S>, ..>` or from an
+/// alias of the [`t`](https://docs.rs/eso/*/eso/shorthand/t/index.html)
+/// module, like `t::EO<E, S, O>`.
+fn eso_parts(ty: &Type) -> Option<[Slot; 3]> {
+    let path = match ty {
+        Type::Path(ty) if ty.qself.is_none() => &ty.path,
+        _ => return None,
+    };
+    let (last, args) = type_args(path)?;
+    if args.len() != 3 {
+        return None;
+    }
+    let n = path.segments.len();
+    if n >= 2 && path.segments[n - 2].ident == "t" {
+        let mut slots = [Slot::Absent, Slot::Absent, Slot::Absent];
+        if last == "None" {
+            return Some(slots);
+        }
+        let mut next = 0;
+        for letter in last.chars() {
+            let i = "eso".find(letter.to_ascii_lowercase())?;
+            if i < next {
+                return None;
+            }
+            next = i + 1;
+            slots[i] = if letter.is_ascii_uppercase() {
+                Slot::Present(Box::new(args[i].clone()))
+            } else {
+                maybe_slot(args[i])
+            };
+        }
+        Some(slots)
+    } else if last == "Eso" {
+        Some([0, 1, 2].map(|i| maybe_slot(args[i])))
+    } else {
+        None
+    }
+}
+
+/// Read a type parameter written as `An<T>` or `No<T>`
+fn maybe_slot(ty: &Type) -> Slot {
+    let path = match ty {
+        Type::Path(ty) if ty.qself.is_none() => &ty.path,
+        _ => return Slot::Unknown,
+    };
+    match type_args(path) {
+        Some((name, args)) if name == "An" && args.len() == 1 => {
+            Slot::Present(Box::new(args[0].clone()))
+        }
+        Some((name, args)) if name == "No" && args.len() == 1 => Slot::Absent,
+        _ => Slot::Unknown,
+    }
+}
+
+/// The name and type arguments of the last segment of a path
+fn type_args(path: &Path) -> Option<(String, Vec<&Type>)> {
+    let last = path.segments.last()?;
+    let args = match &last.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => return None,
+    };
+    Some((last.ident.to_string(), args))
+}
+
+fn newtype_error(input: &DeriveInput) -> Error {
+    Error::new_spanned(
+        &input.ident,
+        "`EsoNewtype` can only be derived for tuple structs with a single `Eso` field, \
+         like `struct X<'a>(Eso<..>)`",
+    )
+}
+
+/// The type `name<..>` with all lifetime parameters replaced by `lifetime`
+fn with_lifetimes(name: &Ident, generics: &Generics, lifetime: &Lifetime) -> Type {
+    let args = generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(_) => quote!(#lifetime),
+        GenericParam::Type(t) => {
+            let ident = &t.ident;
+            quote!(#ident)
+        }
+        GenericParam::Const(c) => {
+            let ident = &c.ident;
+            quote!(#ident)
+        }
+    });
+    if generics.params.is_empty() {
+        parse_quote!(#name)
+    } else {
+        parse_quote!(#name<#(#args),*>)
+    }
+}
+
+/// Replace the given lifetimes in `ty` by `'static`
+fn with_static(ty: &Type, lifetimes: &[&Lifetime]) -> Type {
+    struct ReplaceLifetimes<'l>(&'l [&'l Lifetime]);
+
+    impl VisitMut for ReplaceLifetimes<'_> {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+            if self.0.contains(&&*lifetime) {
+                *lifetime = parse_quote!('static);
+            }
+        }
+    }
+
+    let mut ty = ty.clone();
+    ReplaceLifetimes(lifetimes).visit_type_mut(&mut ty);
+    ty
+}
+
+/// Replace every `'_` in `ty` by `lifetime`
+fn replace_elided(ty: &Type, lifetime: &Lifetime) -> Type {
+    struct ReplaceElided<'l>(&'l Lifetime);
+
+    impl VisitMut for ReplaceElided<'_> {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+            if lifetime.ident == "_" {
+                *lifetime = self.0.clone();
+            }
+        }
+    }
+
+    let mut ty = ty.clone();
+    ReplaceElided(lifetime).visit_type_mut(&mut ty);
+    ty
+}
+
+/// The path to the tuple struct constructor of a newtype
+fn constructor(ty: &Type) -> syn::Result<Path> {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => {
+            let mut path = ty.path.clone();
+            if let Some(last) = path.segments.last_mut() {
+                last.arguments = PathArguments::None;
+            }
+            Ok(path)
+        }
+        _ => Err(Error::new_spanned(
+            ty,
+            "expected the path of a newtype struct, like `MyType<'_>`",
+        )),
+    }
+}
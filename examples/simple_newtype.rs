@@ -36,19 +36,19 @@ impl<'a> WrappedString<'a> {
         WrappedString(Eso::from_cow(s))
     }
 
-    pub fn narrow<'b>(&self) -> WrappedString<'b>
+    pub fn narrow<'b>(self) -> WrappedString<'b>
     where
         'a: 'b,
     {
-        WrappedString(self.0.clone_relax())
+        WrappedString(self.0.narrow())
     }
 
     pub fn into_static(self) -> WrappedString<'static> {
-        WrappedString(self.0.into_static().relax())
+        WrappedString(self.0.into_static_rebound())
     }
 
     pub fn into_owning(self) -> WrappedString<'static> {
-        WrappedString(self.0.into_owning().relax())
+        WrappedString(self.0.into_owning_rebound())
     }
 }
 
@@ -78,7 +78,7 @@ impl AsRef<str> for WrappedString<'_> {
 
 impl<'a, 'b: 'a> Borrow<'a, WrappedString<'a>> for WrappedString<'b> {
     fn borrow(&'a self) -> WrappedString<'a> {
-        self.narrow()
+        WrappedString(self.0.reference().relax())
     }
 }
 
@@ -7,7 +7,7 @@
 //! The [`Take`] and [`Borrow`] traits abstract over the duality
 //! between owned and borrowed types, much in the same way as the
 //! standard-library [`Borrow`](std::borrow::Borrow) and
-//! [`ToOwned`](std::borrow::ToOwned) traits do.
+//! [`ToOwned`] traits do.
 //!
 //! The difference between these and the standard-library traits is
 //! that the traits here are more generic. See the specific traits
@@ -33,6 +33,9 @@
 //! | Static    | [`Borrow`]   |                                                            | [`Take`]
 //! | Owned     | [`Borrow`]   | [`TryInternRef`], [`TryIntern`], [`InternRef`], [`Intern`] |
 //!
+//! The [`Rebind`] trait names the type of an ephemeral reference with
+//! its lifetime replaced, e.g. to shorten it or to make it `'static`.
+//!
 //! As can be seen from the table, there is some additional complexity
 //! regarding the interning operation:
 //!
@@ -84,21 +87,21 @@ pub trait Borrow<'a, T: 'a> {
 impl<'a, T: ?Sized> Borrow<'a, &'a T> for Box<T> {
     #[inline]
     fn borrow(&'a self) -> &'a T {
-        &**self
+        self
     }
 }
 
 impl<'a, T: ?Sized> Borrow<'a, &'a T> for Arc<T> {
     #[inline]
     fn borrow(&'a self) -> &'a T {
-        &**self
+        self
     }
 }
 
 impl<'a, T: ?Sized> Borrow<'a, &'a T> for Rc<T> {
     #[inline]
     fn borrow(&'a self) -> &'a T {
-        &**self
+        self
     }
 }
 
@@ -113,7 +116,7 @@ where
     T: std::borrow::Borrow<R>,
     R: ?Sized + ToOwned<Owned = T>,
 {
-    fn borrow(&'a self) -> Cow<R> {
+    fn borrow(&'a self) -> Cow<'a, R> {
         Cow::Borrowed(self.borrow())
     }
 }
@@ -170,7 +173,7 @@ impl<'a> Borrow<'a, &'a CStr> for CString {
 impl<'a, 'b: 'a, T: ?Sized> Borrow<'a, &'a T> for &'b T {
     #[inline]
     fn borrow(&'a self) -> &'a T {
-        *self
+        self
     }
 }
 
@@ -193,13 +196,13 @@ mod unix {
         }
     }
 
-    impl<'a> Take<OsString> for &'a [u8] {
+    impl Take<OsString> for &[u8] {
         fn to_owned(&self) -> OsString {
             OsStr::from_bytes(self).to_os_string()
         }
     }
 
-    impl<'a> Take<PathBuf> for &'a [u8] {
+    impl Take<PathBuf> for &[u8] {
         fn to_owned(&self) -> PathBuf {
             PathBuf::from(OsStr::from_bytes(self))
         }
@@ -227,72 +230,128 @@ pub trait Take<O>: Sized {
     fn to_owned(&self) -> O;
 }
 
-impl<'a> Take<String> for &'a str {
+impl Take<String> for &str {
     fn to_owned(&self) -> String {
         self.to_string()
     }
 }
 
-impl<'a> Take<PathBuf> for &'a Path {
+impl Take<PathBuf> for &Path {
     fn to_owned(&self) -> PathBuf {
         self.to_path_buf()
     }
 }
 
-impl<'a, T: Clone> Take<Vec<T>> for &'a [T] {
+impl<T: Clone> Take<Vec<T>> for &[T] {
     fn to_owned(&self) -> Vec<T> {
         self.to_vec()
     }
 }
 
-impl<'a> Take<OsString> for &'a OsStr {
+impl Take<OsString> for &OsStr {
     fn to_owned(&self) -> OsString {
         self.to_os_string()
     }
 }
 
-impl<'a> Take<PathBuf> for &'a OsStr {
+impl Take<PathBuf> for &OsStr {
     fn to_owned(&self) -> PathBuf {
         PathBuf::from(self)
     }
 }
 
-impl<'a, T: Clone> Take<T> for &'a T {
+impl<T: Clone> Take<T> for &T {
     fn to_owned(&self) -> T {
         (*self).clone()
     }
 }
 
-impl<'a> Take<CString> for &'a CStr {
+impl Take<CString> for &CStr {
     fn to_owned(&self) -> CString {
         (*self).to_owned()
     }
 }
 
-impl<'a, T: Clone> Take<Box<T>> for &'a T {
+impl<T: Clone> Take<Box<T>> for &T {
     fn to_owned(&self) -> Box<T> {
         Box::new((*self).clone())
     }
 }
 
-impl<'a, T: Clone> Take<Rc<T>> for &'a T {
+impl<T: Clone> Take<Rc<T>> for &T {
     fn to_owned(&self) -> Rc<T> {
         Rc::new((*self).clone())
     }
 }
 
-impl<'a, T: Clone> Take<Arc<T>> for &'a T {
+impl<T: Clone> Take<Arc<T>> for &T {
     fn to_owned(&self) -> Arc<T> {
         Arc::new((*self).clone())
     }
 }
 
+impl<T: Clone> Take<Vec<T>> for Arc<[T]> {
+    fn to_owned(&self) -> Vec<T> {
+        self.to_vec()
+    }
+}
+
+impl<T: Clone> Take<Vec<T>> for Rc<[T]> {
+    fn to_owned(&self) -> Vec<T> {
+        self.to_vec()
+    }
+}
+
 impl<'a, R: ToOwned> Take<R::Owned> for Cow<'a, R> {
     fn to_owned(&self) -> R::Owned {
         self.clone().into_owned()
     }
 }
 
+/// A type-level function giving the type of a generalized reference
+/// with its lifetime replaced by `'b`.
+///
+/// ```
+/// # use eso::borrow::Rebind;
+/// fn shorten<'b, T: Rebind<'b> + 'b>(long: T) -> T::Rebound {
+///     long.narrow()
+/// }
+/// let local = String::from("World");
+/// let both: [&str; 2] = [shorten::<&'static str>("Hello"), &local];
+/// ```
+pub trait Rebind<'b> {
+    /// The type of `Self` with its lifetime replaced by `'b`
+    type Rebound;
+
+    /// Shorten the lifetime of `self` to `'b`, which only needs a
+    /// covariant cast instead of a clone.
+    fn narrow(self) -> Self::Rebound
+    where
+        Self: 'b;
+}
+
+impl<'b, T: ?Sized + 'b> Rebind<'b> for &T {
+    type Rebound = &'b T;
+
+    fn narrow(self) -> &'b T
+    where
+        Self: 'b,
+    {
+        self
+    }
+}
+
+impl<'b, T: ?Sized + ToOwned + 'b> Rebind<'b> for Cow<'_, T> {
+    type Rebound = Cow<'b, T>;
+
+    fn narrow(self) -> Cow<'b, T>
+    where
+        Self: 'b,
+    {
+        self
+    }
+}
+
 macro_rules!forward_trait{
     (@impl@ $to:ty, $fn:ident ( $($decl:tt)* ) -> $out:ty => $wrap:ident($ftrait:ident::$ffn:ident($($use:tt)*)) ) => {
         fn $fn( $($decl)* ) -> $out {
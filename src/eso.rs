@@ -18,7 +18,10 @@
 //! need the additional flexibility compared to the standard
 //! [`Cow`](std::borrow::Cow) type.
 
-use crate::shorthand::x;
+use crate::{
+    maybe::{An, Impossible, Maybe, No},
+    shorthand::x,
+};
 
 // You have been warned:
 // #![allow(clippy::clippy::type_complexity)]
@@ -52,16 +55,88 @@ pub enum Eso<E, S, O> {
     O(O),
 }
 
+/// A plain view of an [`Eso`] without the [`Maybe`] wrappers,
+/// as returned by [`Eso::variant`] and [`Eso::into_variant`].
+///
+/// The type parameters of the variants that the [`Eso`] cannot hold
+/// are [`Infallible`](std::convert::Infallible), so a `match` only
+/// needs arms for the variants that can actually occur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variant<E, S, O> {
+    /// The ephemeral value
+    E(E),
+    /// The static or shared value
+    S(S),
+    /// The owned value
+    O(O),
+}
+
 /// An [`Eso`] of [`Eso`]s, but the inner [`Eso`]s are constrained
 /// to definitely contain the corresponding varient.
 pub type ConstrainedEsoOfEso<E, S, O> = Eso<x::E<E, S, O>, x::S<E, S, O>, x::O<E, S, O>>;
 
+/// Names the inner types of the three variants of an [`Eso`].
+///
+/// This allows code that only knows the [`Eso`] type as a whole,
+/// e.g. through a type alias or in a macro, to refer to its parts.
+///
+/// ```
+/// # use eso::{eso::EsoTypes, shorthand::t};
+/// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+/// let owned: <Str as EsoTypes>::O = String::from("Hello");
+/// ```
+pub trait EsoTypes {
+    /// The inner type of the [`E`](Eso::E) variant
+    type E;
+    /// The inner type of the [`S`](Eso::S) variant
+    type S;
+    /// The inner type of the [`O`](Eso::O) variant
+    type O;
+}
+
+impl<ME: Maybe, MS: Maybe, MO: Maybe> EsoTypes for Eso<ME, MS, MO> {
+    type E = ME::Inner;
+    type S = MS::Inner;
+    type O = MO::Inner;
+}
+
+/// A [`Maybe`] that may hold an [`Eso`], as used by [`Eso::flatten`].
+///
+/// An [`An`] yields the [`Eso`] it holds, while a [`No`] stands for an
+/// [`Eso`] that cannot hold any value, whatever its inner type.
+pub trait MaybeEso: Maybe {
+    /// The [`Eso`] that is held, or an [`Eso`] of [`No`]s
+    type Eso;
+
+    /// Unwrap the [`Eso`]
+    fn into_eso(self) -> Self::Eso;
+}
+
+impl<ME, MS, MO> MaybeEso for An<Eso<ME, MS, MO>> {
+    type Eso = Eso<ME, MS, MO>;
+
+    fn into_eso(self) -> Self::Eso {
+        self.0
+    }
+}
+
+impl<A> MaybeEso for No<A> {
+    type Eso = Eso<No<()>, No<()>, No<()>>;
+
+    fn into_eso(self) -> Self::Eso {
+        self.absurd()
+    }
+}
+
 /// Functions to create new [`Eso`]s
 mod create;
 
 /// Functions to access the referenced object
 mod inside;
 
+/// Iteration over the contents of an [`Eso`]
+mod iter;
+
 /// Functions to manipulate the contained refrerences/values
 mod manipulate;
 
@@ -74,4 +149,14 @@ mod query;
 /// Functions to change the state of an [`Eso`]
 mod transform;
 
+/// Moving an [`Option`] or [`Result`] out of an [`Eso`]
+mod transpose;
+
+/// Visiting the contents of an [`Eso`] with a shared context
+mod visit;
+
 pub mod req;
+
+pub use self::iter::IntoIter;
+pub use self::transpose::{Rewrap, Transpose};
+pub use self::visit::Visitor;
@@ -22,7 +22,6 @@ impl<E, MS, MO> Eso<An<E>, MS, MO> {
     /// let my_string = String::from("Hello World");
     /// let my_str = make_a_str(my_string.as_str());
     /// ```
-
     pub const fn from_ref(e: E) -> Self {
         Eso::E(An(e))
     }
@@ -6,12 +6,18 @@
 
 use crate::{
     eso::req::{MBorrow, MTake, MUnwrapInto},
-    maybe::{An, Impossible, Maybe, No},
+    maybe::{An, Impossible, Maybe, MaybeAsMut, MaybeAsRef, MaybeUnwrap, No},
 };
 
 use super::*;
 
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    ffi::{CStr, CString, OsStr, OsString},
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
 
 impl<ME, MS, O> Eso<ME, MS, An<O>> {
     /// Get a mutable reference to the contained owned value,
@@ -101,6 +107,184 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
         }
     }
 
+    /// Borrow the contents of this [`Eso`], giving an [`Eso`] of
+    /// references with the same variants present, as
+    /// [`Option::as_ref`] does.
+    ///
+    /// This allows to use the consuming functions like
+    /// [`merge_with`](Eso::merge_with) on a borrowed [`Eso`]:
+    ///
+    /// ```
+    /// # use eso::{maybe::{An, No}, shorthand::t, Eso};
+    /// type Str<'a> = t::EO<&'a str, &'static str, String>;
+    /// let my_str = Str::from_owned("Hello World".to_string());
+    /// let borrowed: Eso<An<&&str>, No<&&str>, An<&String>> = my_str.as_ref();
+    /// let len = borrowed.merge_with(|e| e.len(), |s| s.len(), |o| o.len());
+    /// assert_eq!(len, 11);
+    /// assert!(my_str.is_owning());
+    /// ```
+    pub fn as_ref<'a>(&'a self) -> Eso<ME::Ref, MS::Ref, MO::Ref>
+    where
+        ME: MaybeAsRef<'a>,
+        MS: MaybeAsRef<'a>,
+        MO: MaybeAsRef<'a>,
+    {
+        match self {
+            Eso::E(e) => Eso::E(e.maybe_as_ref()),
+            Eso::S(s) => Eso::S(s.maybe_as_ref()),
+            Eso::O(o) => Eso::O(o.maybe_as_ref()),
+        }
+    }
+
+    /// Mutably borrow the contents of this [`Eso`], giving an [`Eso`]
+    /// of mutable references with the same variants present, as
+    /// [`Option::as_mut`] does.
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Int<'a> = t::ESO<&'a i32, &'static i32, i32>;
+    /// let mut my_int = Int::from_owned(40);
+    /// my_int.as_mut().map_o(|o| *o += 2);
+    /// assert_eq!(my_int.get_ref::<&i32>(), &42);
+    ///
+    /// let mut my_int = Int::from_static(&40);
+    /// my_int.as_mut().map_s(|s| *s = &42);
+    /// assert_eq!(my_int.get_ref::<&i32>(), &42);
+    /// ```
+    pub fn as_mut<'a>(&'a mut self) -> Eso<ME::Mut, MS::Mut, MO::Mut>
+    where
+        ME: MaybeAsMut<'a>,
+        MS: MaybeAsMut<'a>,
+        MO: MaybeAsMut<'a>,
+    {
+        match self {
+            Eso::E(e) => Eso::E(e.maybe_as_mut()),
+            Eso::S(s) => Eso::S(s.maybe_as_mut()),
+            Eso::O(o) => Eso::O(o.maybe_as_mut()),
+        }
+    }
+
+    /// Look at the contents of this [`Eso`] through a plain [`Variant`]
+    /// of references.
+    ///
+    /// The variants that cannot exist have the uninhabited type
+    /// [`Infallible`](std::convert::Infallible), so they need no arm:
+    ///
+    /// ```
+    /// # use eso::{eso::Variant, shorthand::t};
+    /// type Str<'a> = t::SO<&'a str, &'static str, String>;
+    /// fn describe(s: &Str) -> String {
+    ///     match s.variant() {
+    ///         Variant::S(s) => format!("static {}", s),
+    ///         Variant::O(o) => format!("owned {}", o),
+    ///     }
+    /// }
+    /// assert_eq!(describe(&Str::from_static("Hello")), "static Hello");
+    /// assert_eq!(describe(&Str::from_owned("World".to_string())), "owned World");
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn variant<'a>(
+        &'a self,
+    ) -> Variant<
+        <ME::Ref as MaybeUnwrap>::Value,
+        <MS::Ref as MaybeUnwrap>::Value,
+        <MO::Ref as MaybeUnwrap>::Value,
+    >
+    where
+        ME: MaybeAsRef<'a>,
+        MS: MaybeAsRef<'a>,
+        MO: MaybeAsRef<'a>,
+        ME::Ref: MaybeUnwrap,
+        MS::Ref: MaybeUnwrap,
+        MO::Ref: MaybeUnwrap,
+    {
+        self.as_ref().into_variant()
+    }
+
+    /// Turn this [`Eso`] into a plain [`Variant`], see
+    /// [`variant`](Eso::variant).
+    ///
+    /// ```
+    /// # use eso::{eso::Variant, shorthand::t};
+    /// type Str<'a> = t::EO<&'a str, &'static str, String>;
+    /// match Str::from_ref("Hello").into_variant() {
+    ///     Variant::E(e) => assert_eq!(e, "Hello"),
+    ///     Variant::O(_) => unreachable!(),
+    /// }
+    /// ```
+    pub fn into_variant(self) -> Variant<ME::Value, MS::Value, MO::Value>
+    where
+        ME: MaybeUnwrap,
+        MS: MaybeUnwrap,
+        MO: MaybeUnwrap,
+    {
+        match self {
+            Eso::E(e) => Variant::E(e.unwrap_value()),
+            Eso::S(s) => Variant::S(s.unwrap_value()),
+            Eso::O(o) => Variant::O(o.unwrap_value()),
+        }
+    }
+
+    /// Borrow the ephemeral reference contained in this [`Eso`],
+    /// if it actually contains one:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// assert_eq!(Str::from_ref("Hello").get_ephemeral(), Some(&"Hello"));
+    /// assert_eq!(Str::from_static("Hello").get_ephemeral(), None);
+    /// ```
+    pub fn get_ephemeral(&self) -> Option<&ME::Inner>
+    where
+        ME: Maybe,
+    {
+        match self {
+            Eso::E(e) => Some(e.inner()),
+            _ => None,
+        }
+    }
+
+    /// Borrow the static reference or shared value contained in
+    /// this [`Eso`], if it actually contains one:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// assert_eq!(Str::from_static("Hello").get_static(), Some(&"Hello"));
+    /// assert_eq!(Str::from_ref("Hello").get_static(), None);
+    /// ```
+    pub fn get_static(&self) -> Option<&MS::Inner>
+    where
+        MS: Maybe,
+    {
+        match self {
+            Eso::S(s) => Some(s.inner()),
+            _ => None,
+        }
+    }
+
+    /// Borrow the owned value contained in this [`Eso`], if it
+    /// actually contains one:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let owned = Str::from_owned("Hello".to_string());
+    /// assert_eq!(owned.get_owned(), Some(&"Hello".to_string()));
+    /// assert_eq!(Str::from_ref("Hello").get_owned(), None);
+    /// ```
+    ///
+    /// See [`try_get_mut`](Eso::try_get_mut) for mutable access.
+    pub fn get_owned(&self) -> Option<&MO::Inner>
+    where
+        MO: Maybe,
+    {
+        match self {
+            Eso::O(o) => Some(o.inner()),
+            _ => None,
+        }
+    }
+
     /// Mutably borrow the owned value contained in this [`Eso`],
     /// if it actually contains an owned value:
     ///
@@ -179,6 +363,41 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
             Eso::O(o) => o.unwrap(),
         }
     }
+
+    /// Extract the contained value, converting it into a `T` with
+    /// [`Into`], which all of the inner types must support.
+    ///
+    /// ```
+    /// # use eso::shorthand::t; use std::sync::Arc;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let boxed: Box<str> = Str::from_ref("Hello").merge_into();
+    /// let shared: Arc<str> = Str::from_owned("World".into()).merge_into::<Arc<str>>();
+    /// assert_eq!(format!("{} {}", boxed, shared), "Hello World");
+    /// ```
+    ///
+    /// The common target types of the standard library can also be
+    /// created with [`From`]:
+    ///
+    /// ```
+    /// # use eso::shorthand::t; use std::path::PathBuf;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// type Slice<'a> = t::ESO<&'a [u8], &'static [u8], Vec<u8>>;
+    /// assert_eq!(String::from(Str::from_static("Hello")), "Hello");
+    /// assert_eq!(PathBuf::from(Str::from_ref("/tmp")), PathBuf::from("/tmp"));
+    /// assert_eq!(Vec::from(Slice::from_ref(b"bytes")), b"bytes");
+    /// ```
+    pub fn merge_into<T>(self) -> T
+    where
+        ME: MUnwrapInto<T>,
+        MS: MUnwrapInto<T>,
+        MO: MUnwrapInto<T>,
+    {
+        match self {
+            Eso::E(e) => e.unwrap_into(),
+            Eso::S(s) => s.unwrap_into(),
+            Eso::O(o) => o.unwrap_into(),
+        }
+    }
 }
 
 impl<E, S, O> Eso<An<E>, No<S>, No<O>> {
@@ -323,3 +542,55 @@ impl<E, S, O> Eso<No<E>, An<S>, No<O>> {
         }
     }
 }
+
+macro_rules! impl_from_eso {
+    ($g:ident => $($t:ty),+ $(,)?) => {
+        $(
+            impl<$g, ME, MS, MO> From<Eso<ME, MS, MO>> for $t
+            where
+                ME: MUnwrapInto<$t>,
+                MS: MUnwrapInto<$t>,
+                MO: MUnwrapInto<$t>,
+            {
+                fn from(eso: Eso<ME, MS, MO>) -> Self {
+                    eso.merge_into()
+                }
+            }
+        )+
+    };
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl<ME, MS, MO> From<Eso<ME, MS, MO>> for $t
+            where
+                ME: MUnwrapInto<$t>,
+                MS: MUnwrapInto<$t>,
+                MO: MUnwrapInto<$t>,
+            {
+                fn from(eso: Eso<ME, MS, MO>) -> Self {
+                    eso.merge_into()
+                }
+            }
+        )+
+    };
+}
+
+impl_from_eso!(
+    String,
+    PathBuf,
+    OsString,
+    CString,
+    Box<str>,
+    Box<Path>,
+    Box<OsStr>,
+    Box<CStr>,
+    Arc<str>,
+    Arc<Path>,
+    Arc<OsStr>,
+    Arc<CStr>,
+    Rc<str>,
+    Rc<Path>,
+    Rc<OsStr>,
+    Rc<CStr>,
+);
+
+impl_from_eso!(T => Vec<T>, Box<[T]>, Arc<[T]>, Rc<[T]>);
@@ -0,0 +1,94 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::maybe::{MaybeIntoIter, MaybeIter};
+
+use super::*;
+
+/// Iterate over the contents of an [`Eso`] while keeping track
+/// of which variant the items came from.
+///
+/// Each inner type that may be present must be [`IntoIterator`],
+/// and every item will be wrapped in the same variant as the
+/// collection it came from:
+///
+/// ```
+/// # use eso::shorthand::t;
+/// type Slice<'a> = t::ESO<&'a [i32], &'static [i32], Vec<i32>>;
+/// let numbers = vec![1, 2, 3];
+///
+/// let borrowed = Slice::from_ref(&numbers);
+/// for item in borrowed {
+///     assert!(item.is_ephemeral());
+/// }
+///
+/// let owned = Slice::from_owned(numbers);
+/// let items: Vec<i32> = owned.into_iter()
+///     .map(|item| item.try_unwrap_owned().unwrap())
+///     .collect();
+/// assert_eq!(items, vec![1, 2, 3]);
+/// ```
+///
+/// A [`No`] stays a [`No`], so its
+/// inner type does not need to be [`IntoIterator`]:
+///
+/// ```
+/// # use eso::shorthand::t; use std::sync::Arc;
+/// type Slice<'a> = t::EO<&'a [i32], Arc<[i32]>, Vec<i32>>;
+/// type Item<'a> = t::EO<&'a i32, Arc<[i32]>, i32>;
+/// let items: Vec<Item> = Slice::from_owned(vec![1, 2]).into_iter().collect();
+/// assert!(items.iter().all(|item| item.is_owning()));
+/// ```
+impl<ME, MS, MO> IntoIterator for Eso<ME, MS, MO>
+where
+    ME: MaybeIntoIter,
+    MS: MaybeIntoIter,
+    MO: MaybeIntoIter,
+{
+    type Item = Eso<
+        <ME::Iter as MaybeIter>::Item,
+        <MS::Iter as MaybeIter>::Item,
+        <MO::Iter as MaybeIter>::Item,
+    >;
+    type IntoIter = IntoIter<ME::Iter, MS::Iter, MO::Iter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.outer_map(
+            ME::into_maybe_iter,
+            MS::into_maybe_iter,
+            MO::into_maybe_iter,
+        ))
+    }
+}
+
+/// The iterator returned by [`Eso::into_iter`](IntoIterator::into_iter)
+#[derive(Debug, Clone)]
+pub struct IntoIter<IE, IS, IO>(Eso<IE, IS, IO>);
+
+impl<IE, IS, IO> Iterator for IntoIter<IE, IS, IO>
+where
+    IE: MaybeIter,
+    IS: MaybeIter,
+    IO: MaybeIter,
+{
+    type Item = Eso<IE::Item, IS::Item, IO::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            Eso::E(e) => e.next_item().map(Eso::E),
+            Eso::S(s) => s.next_item().map(Eso::S),
+            Eso::O(o) => o.next_item().map(Eso::O),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.0 {
+            Eso::E(e) => e.item_size_hint(),
+            Eso::S(s) => s.item_size_hint(),
+            Eso::O(o) => o.item_size_hint(),
+        }
+    }
+}
@@ -4,7 +4,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::maybe::{Maybe, MaybeMap};
+use crate::{
+    maybe::{Maybe, MaybeMap, MaybeTryMap},
+    zip::{Zip, ZipRef},
+};
 
 use super::*;
 
@@ -126,6 +129,189 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
         }
     }
 
+    /// Transform `self´ by applying a fallible function to the `E`
+    /// variant while preserving the other variants.
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Num<'a> = t::ESO<&'a str, &'static str, i64>;
+    /// let parsed = Num::from_ref("42").try_map_e(str::parse::<i64>);
+    /// assert_eq!(parsed.unwrap().get_ephemeral(), Some(&42));
+    ///
+    /// assert!(Num::from_ref("forty-two").try_map_e(str::parse::<i64>).is_err());
+    /// assert!(Num::from_static("forty-two").try_map_e(str::parse::<i64>).is_ok());
+    /// ```
+    pub fn try_map_e<F, T, Err>(self, f: F) -> Result<Eso<ME::Out, MS, MO>, Err>
+    where
+        ME: MaybeTryMap<T>,
+        F: FnOnce(ME::Inner) -> Result<T, Err>,
+    {
+        Ok(match self {
+            Eso::E(e) => Eso::E(e.do_try_map(f)?),
+            Eso::S(s) => Eso::S(s),
+            Eso::O(o) => Eso::O(o),
+        })
+    }
+
+    /// Transform `self´ by applying a fallible function to the `S`
+    /// variant while preserving the other variants.
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Num<'a> = t::ESO<&'a str, &'static str, i64>;
+    /// let parsed = Num::from_static("42").try_map_s(str::parse::<i64>);
+    /// assert_eq!(parsed.unwrap().get_static(), Some(&42));
+    ///
+    /// assert!(Num::from_static("forty-two").try_map_s(str::parse::<i64>).is_err());
+    /// ```
+    pub fn try_map_s<F, T, Err>(self, f: F) -> Result<Eso<ME, MS::Out, MO>, Err>
+    where
+        MS: MaybeTryMap<T>,
+        F: FnOnce(MS::Inner) -> Result<T, Err>,
+    {
+        Ok(match self {
+            Eso::E(e) => Eso::E(e),
+            Eso::S(s) => Eso::S(s.do_try_map(f)?),
+            Eso::O(o) => Eso::O(o),
+        })
+    }
+
+    /// Transform `self´ by applying a fallible function to the `O`
+    /// variant while preserving the other variants.
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let checked = Str::from_owned("Hello".to_string()).try_map_o(|o| {
+    ///     if o.is_empty() { Err("empty") } else { Ok(o.into_boxed_str()) }
+    /// });
+    /// assert_eq!(checked.unwrap().get_owned().map(|o| &**o), Some("Hello"));
+    /// ```
+    pub fn try_map_o<F, T, Err>(self, f: F) -> Result<Eso<ME, MS, MO::Out>, Err>
+    where
+        MO: MaybeTryMap<T>,
+        F: FnOnce(MO::Inner) -> Result<T, Err>,
+    {
+        Ok(match self {
+            Eso::E(e) => Eso::E(e),
+            Eso::S(s) => Eso::S(s),
+            Eso::O(o) => Eso::O(o.do_try_map(f)?),
+        })
+    }
+
+    /// Transform `self´ by applying a different fallible function to
+    /// each of the variants, returning the first error.
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// use std::num::ParseIntError;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// type Num = t::ESO<i64, i64, i64>;
+    /// fn parse(s: Str) -> Result<Num, ParseIntError> {
+    ///     s.try_map(str::parse, str::parse, |o| o.trim().parse())
+    /// }
+    /// assert!(parse(Str::from_ref("1")).unwrap().is_ephemeral());
+    /// assert!(parse(Str::from_static("2")).unwrap().is_static());
+    /// assert_eq!(parse(Str::from_owned(" 3 ".into())).unwrap().get_owned(), Some(&3));
+    /// assert!(parse(Str::from_ref("four")).is_err());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_map<EF, ET, SF, ST, OF, OT, Err>(
+        self,
+        ef: EF,
+        sf: SF,
+        of: OF,
+    ) -> Result<Eso<ME::Out, MS::Out, MO::Out>, Err>
+    where
+        ME: MaybeTryMap<ET>,
+        MS: MaybeTryMap<ST>,
+        MO: MaybeTryMap<OT>,
+        EF: FnOnce(ME::Inner) -> Result<ET, Err>,
+        SF: FnOnce(MS::Inner) -> Result<ST, Err>,
+        OF: FnOnce(MO::Inner) -> Result<OT, Err>,
+    {
+        Ok(match self {
+            Eso::E(e) => Eso::E(e.do_try_map(ef)?),
+            Eso::S(s) => Eso::S(s.do_try_map(sf)?),
+            Eso::O(o) => Eso::O(o.do_try_map(of)?),
+        })
+    }
+
+    /// Combine `self` and `other` into an [`Eso`] of pairs, choosing
+    /// the weaker of the two variants: an `S` if both are `S`, and an
+    /// `O` otherwise.
+    ///
+    /// The values are moved into the result. Only a static value that
+    /// is paired with an owned one is converted, by [`Take::own`](crate::borrow::Take::own), see
+    /// the [`zip`](crate::zip) module for the details. Neither of the
+    /// two can be ephemeral, as the result would have to borrow from
+    /// the other one; use [`zip_ref`](Self::zip_ref) for those.
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str = t::SO<&'static str, &'static str, String>;
+    /// let (key, value) = (Str::from_static("greeting"), Str::from_static("Hello"));
+    /// assert!(key.zip(value).is_static());
+    ///
+    /// let (key, value) = (Str::from_static("greeting"), Str::from_owned("Hello".into()));
+    /// let pair = key.zip(value);
+    /// assert_eq!(pair.get_owned(), Some(&("greeting".to_string(), "Hello".to_string())));
+    /// ```
+    ///
+    /// The variants of the result that cannot occur are [`No`]:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Key = t::S<&'static str, &'static str, String>;
+    /// type Value = t::O<&'static str, &'static str, String>;
+    /// let (key, value) = (Key::from_static("k"), Value::from_owned("v".into()));
+    /// let pair: t::O<_, _, _> = key.zip(value);
+    /// assert!(pair.is_owning());
+    /// ```
+    pub fn zip<B>(self, other: B) -> <Self as Zip<B>>::Out
+    where
+        Self: Zip<B>,
+    {
+        Zip::zip(self, other)
+    }
+
+    /// Combine `self` and `other` into an [`Eso`] of pairs, like
+    /// [`zip`](Self::zip), but borrowing both of them: an `E` if
+    /// either of them is an `E`, with the other one borrowed by
+    /// [`Borrow`](crate::borrow::Borrow), an `S` if both are `S`, and
+    /// an `O` otherwise.
+    ///
+    /// As `self` and `other` are only borrowed, the values of an `S`
+    /// or `O` result are cloned, and a static value paired with an
+    /// owned one is cloned before it is taken by [`Take::own`](crate::borrow::Take::own).
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let value = Str::from_owned("Hello".to_string());
+    /// let input = String::from("greeting");
+    /// let key = Str::from_ref(&input);
+    /// let pair = key.zip_ref(&value);
+    /// assert_eq!(pair.get_ephemeral(), Some(&("greeting", "Hello")));
+    /// ```
+    ///
+    /// The variants of the result that cannot occur are [`No`]:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Key<'a> = t::ES<&'a str, &'static str, String>;
+    /// type Value = t::O<&'static str, &'static str, String>;
+    /// let (key, value) = (Key::from_static("k"), Value::from_owned("v".into()));
+    /// let pair: t::EO<_, _, _> = key.zip_ref(&value);
+    /// assert!(pair.is_owning());
+    /// ```
+    pub fn zip_ref<'a, B>(&'a self, other: &'a B) -> <Self as ZipRef<'a, B>>::Out
+    where
+        Self: ZipRef<'a, B>,
+    {
+        ZipRef::zip_ref(self, other)
+    }
+
     /// Special case of [`merge_with`](Self::merge_with) to match the expected
     /// name for operations that map a contained value into the container type.
     pub fn flat_map<EF, SF, OF, ME1, MS1, MO1>(self, ef: EF, sf: SF, of: OF) -> Eso<ME1, MS1, MO1>
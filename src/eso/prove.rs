@@ -5,9 +5,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::{
-    maybe::{An, Impossible, Maybe, No, Relax},
+    maybe::{An, And, Impossible, Maybe, No, Normalize, Not, Or, Relax},
     shorthand::t,
-    unify::Unify3,
+    unify::{Unify3, UnifyVia3},
 };
 
 use super::*;
@@ -282,6 +282,40 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
         }
     }
 
+    /// Replace the inner type of every [`No`] type parameter by `()`,
+    /// according to [`Normalize`].
+    ///
+    /// Esos that only differ in the inner types of absent variants
+    /// become the same type after normalizing, and a normalized [`Eso`]
+    /// can be [`relax`](Eso::relax)ed into any [`Eso`] that has the
+    /// same present variants:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Borrowed<'a> = t::E<&'a str, &'static str, String>;
+    /// type Literal<'a> = t::S<&'a str, &'static str, Box<str>>;
+    /// type Either<'a> = t::ES<&'a str, &'static str, ()>;
+    ///
+    /// let local = String::from("World");
+    /// let words: Vec<Either> = vec![
+    ///     Literal::from_static("Hello").normalize().relax(),
+    ///     Borrowed::from_ref(&local).normalize().relax(),
+    /// ];
+    /// assert!(words[0].is_static() && words[1].is_ephemeral());
+    /// ```
+    pub fn normalize(self) -> Eso<ME::Out, MS::Out, MO::Out>
+    where
+        ME: Normalize,
+        MS: Normalize,
+        MO: Normalize,
+    {
+        match self {
+            Eso::E(e) => Eso::E(e.normalize()),
+            Eso::S(s) => Eso::S(s.normalize()),
+            Eso::O(o) => Eso::O(o.normalize()),
+        }
+    }
+
     /// Clone the [`Eso`] with relaxed type parameters, to fit
     /// an expected configuration. See [`Eso::relax`] for
     /// more information.
@@ -369,6 +403,83 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
             Eso::O(o) => ME::inject3_c(o),
         }
     }
+
+    /// Merge the three possible values into one like [`unify`](Eso::unify),
+    /// but allow the inner [`Eso`]s to hold different types, converting
+    /// them into the inner types of the target [`Eso`] `T` according to
+    /// the rules of [`UnifyVia`](crate::unify::UnifyVia):
+    ///
+    /// ```
+    /// # use ::eso::{eso::*, shorthand::*};
+    /// # use std::sync::Arc;
+    /// type Literal = t::S<&'static str, &'static str, String>;
+    /// type Interned = t::S<&'static str, Arc<str>, String>;
+    /// type Owned = t::O<&'static str, &'static str, String>;
+    /// type Nested = Eso<Owned, Literal, Interned>;
+    /// type Target = t::ESO<&'static str, Arc<str>, String>;
+    /// type Merged = t::SO<&'static str, Arc<str>, String>;
+    ///
+    /// let eso1: Merged = Nested::E(Owned::from_owned("Hello".into())).unify_via::<Target>();
+    /// let eso2: Merged = Nested::S(Literal::from_static("Hello")).unify_via::<Target>();
+    /// let eso3: Merged = Nested::O(Interned::from_static("Hello".into())).unify_via::<Target>();
+    /// assert!(eso1.is_owning());
+    /// assert!(eso2.is_static() && eso3.is_static());
+    /// ```
+    pub fn unify_via<T>(self) -> ME::Out3
+    where
+        ME: UnifyVia3<MS, MO, T>,
+    {
+        match self {
+            Eso::E(e) => ME::inject3_a(e),
+            Eso::S(s) => ME::inject3_b(s),
+            Eso::O(o) => ME::inject3_c(o),
+        }
+    }
+}
+
+impl<ME: MaybeEso, MS: MaybeEso, MO: MaybeEso> Eso<ME, MS, MO> {
+    /// Flatten an [`Eso`] of [`Eso`]s, as produced e.g. by
+    /// [`map`](Eso::map), into a single [`Eso`] holding the value of
+    /// the inner [`Eso`].
+    ///
+    /// The type parameters of the inner [`Eso`]s are merged according to
+    /// the rules of [`unify`](crate::unify), and an absent variant of
+    /// the outer [`Eso`] does not contribute anything, whatever its
+    /// inner type:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// type Lasting = t::SO<&'static str, &'static str, String>;
+    /// type Temporary<'a> = t::E<&'a str, &'static str, String>;
+    ///
+    /// fn classify<'a>(s: Str<'a>) -> t::ESO<&'a str, &'static str, String> {
+    ///     s.map(
+    ///         Temporary::from_ref,
+    ///         Lasting::from_static,
+    ///         |o| if o.is_empty() { Lasting::from_static("") } else { Lasting::from_owned(o) },
+    ///     )
+    ///     .flatten()
+    /// }
+    ///
+    /// assert!(classify(Str::from_ref("Hello")).is_ephemeral());
+    /// assert!(classify(Str::from_owned(String::new())).is_static());
+    /// assert!(classify(Str::from_owned("Hello".into())).is_owning());
+    /// ```
+    ///
+    /// For [`Eso`]s that hold other [`Eso`]s directly, without [`Maybe`]
+    /// wrappers, as e.g. [`split`](Eso::split) produces, use
+    /// [`unify`](Eso::unify) instead.
+    pub fn flatten(self) -> <ME::Eso as Unify3<MS::Eso, MO::Eso>>::Out3
+    where
+        ME::Eso: Unify3<MS::Eso, MO::Eso>,
+    {
+        match self {
+            Eso::E(e) => ME::Eso::inject3_a(e.into_eso()),
+            Eso::S(s) => ME::Eso::inject3_b(s.into_eso()),
+            Eso::O(o) => ME::Eso::inject3_c(o.into_eso()),
+        }
+    }
 }
 
 impl<E, S, O, ES, EO, SE, SO, OE, OS> Eso<t::E<E, ES, EO>, t::S<SE, S, SO>, t::O<OE, OS, O>> {
@@ -379,6 +490,47 @@ impl<E, S, O, ES, EO, SE, SO, OE, OS> Eso<t::E<E, ES, EO>, t::S<SE, S, SO>, t::O
     }
 }
 
+impl<ME, MS, MO, ME1, MS1, MO1> Relax<Eso<ME1, MS1, MO1>> for Eso<ME, MS, MO>
+where
+    ME: Relax<ME1>,
+    MS: Relax<MS1>,
+    MO: Relax<MO1>,
+{
+    fn relax(self) -> Eso<ME1, MS1, MO1> {
+        Eso::relax(self)
+    }
+}
+
+impl<AE, AS, AO, BE, BS, BO> And<Eso<BE, BS, BO>> for Eso<AE, AS, AO>
+where
+    AE: And<BE>,
+    AS: And<BS>,
+    AO: And<BO>,
+{
+    type Out = Eso<AE::Out, AS::Out, AO::Out>;
+}
+
+impl<AE, AS, AO, BE, BS, BO> Or<Eso<BE, BS, BO>> for Eso<AE, AS, AO>
+where
+    AE: Or<BE>,
+    AS: Or<BS>,
+    AO: Or<BO>,
+{
+    type Out = Eso<AE::Out, AS::Out, AO::Out>;
+}
+
+impl<ME: Not, MS: Not, MO: Not> Not for Eso<ME, MS, MO> {
+    type Out = Eso<ME::Out, MS::Out, MO::Out>;
+}
+
+impl<ME: Normalize, MS: Normalize, MO: Normalize> Normalize for Eso<ME, MS, MO> {
+    type Out = Eso<ME::Out, MS::Out, MO::Out>;
+
+    fn normalize(self) -> Self::Out {
+        Eso::normalize(self)
+    }
+}
+
 impl<E, S, O> Impossible for Eso<No<E>, No<S>, No<O>> {
     fn absurd<T>(&self) -> T {
         match self {
@@ -4,6 +4,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use crate::maybe::Maybe;
+
 use super::*;
 
 /// Functions to ask about the status of an [`Eso`].
@@ -105,3 +107,17 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
         !matches!(self, Eso::E(_))
     }
 }
+
+impl<ME: Maybe, MS: Maybe, MO: Maybe> Eso<ME, MS, MO> {
+    /// Which of the variants `E`, `S` and `O` the type can hold,
+    /// i.e. which of its type parameters are [`An`].
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// type Lasting<'a> = t::SO<&'a str, &'static str, String>;
+    /// assert_eq!(Str::SHAPE, (true, true, true));
+    /// assert_eq!(Lasting::SHAPE, (false, true, true));
+    /// ```
+    pub const SHAPE: (bool, bool, bool) = (ME::PRESENT, MS::PRESENT, MO::PRESENT);
+}
@@ -5,14 +5,19 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::{
+    borrow::Rebind,
     eso::{
         req::{MBorrow, MIntern, MInternRef, MTake, MTryIntern, MTryInternRef},
         Eso,
     },
-    maybe::{An, Maybe},
+    maybe::{An, Maybe, MaybeMap},
     shorthand::x,
 };
 
+/// The `E` type parameter `ME` with the lifetime of its inner
+/// type replaced by `'b` according to [`Rebind`]
+type Rebound<'b, ME> = <ME as MaybeMap<<<ME as Maybe>::Inner as Rebind<'b>>::Rebound>>::Out;
+
 /// Methods to transform an [`Eso`] between its different states.
 impl<ME, MS, MO> Eso<ME, MS, MO> {
     /// Transform this [`Eso`] into one that can only be a static/shared
@@ -63,6 +68,10 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
     /// }
     /// my_fn("Hello World");
     /// ```
+    ///
+    /// If the type of the ephemeral reference is [`Rebind`],
+    /// [`into_static_rebound`](Eso::into_static_rebound) does this in
+    /// one step.
     pub fn into_static(self) -> x::sO<ME, MS, MO>
     where
         ME: MTake<MO::Inner>,
@@ -111,6 +120,61 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
         }
     }
 
+    /// Transform this [`Eso`] like [`into_static`](Eso::into_static),
+    /// but keep the `E` type parameter with its lifetime replaced by
+    /// `'static` according to [`Rebind`], so that the result has the
+    /// same type as the `'static` form of `self`:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// # fn function_consuming_static<T: 'static>(_: T) {}
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// fn my_fn(borrowed: &str) -> Str<'static> {
+    ///     Str::from_ref(borrowed).into_static_rebound()
+    /// }
+    /// let my_static = my_fn(&String::from("Hello World"));
+    /// assert!(my_static.is_owning());
+    /// function_consuming_static(my_static);
+    /// ```
+    pub fn into_static_rebound(self) -> Eso<Rebound<'static, ME>, MS, An<MO::Inner>>
+    where
+        ME: MTake<MO::Inner> + MaybeMap<<<ME as Maybe>::Inner as Rebind<'static>>::Rebound>,
+        ME::Inner: Rebind<'static>,
+        MO: Maybe,
+    {
+        match self {
+            Eso::E(e) => Eso::O(An(e.own())),
+            Eso::S(s) => Eso::S(s),
+            Eso::O(o) => Eso::O(An(o.unwrap())),
+        }
+    }
+
+    /// Transform this [`Eso`] like [`into_owning`](Eso::into_owning),
+    /// but keep the type parameters, with the lifetime of the `E` type
+    /// replaced by `'static` according to [`Rebind`]:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// fn my_fn(borrowed: &str) -> Str<'static> {
+    ///     Str::from_ref(borrowed).into_owning_rebound()
+    /// }
+    /// assert!(my_fn(&String::from("Hello World")).is_owning());
+    /// ```
+    pub fn into_owning_rebound(self) -> Eso<Rebound<'static, ME>, MS, An<MO::Inner>>
+    where
+        ME: MTake<MO::Inner> + MaybeMap<<<ME as Maybe>::Inner as Rebind<'static>>::Rebound>,
+        ME::Inner: Rebind<'static>,
+        MS: MTake<MO::Inner>,
+        MO: Maybe,
+    {
+        match self {
+            Eso::E(e) => Eso::O(An(e.own())),
+            Eso::S(s) => Eso::O(An(s.own())),
+            Eso::O(o) => Eso::O(An(o.unwrap())),
+        }
+    }
+
     /// Transform this [`Eso`] into one that is definitely an owned value.
     ///
     /// Any reference will be cloned into an owned form via
@@ -164,6 +228,32 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
         }
     }
 
+    /// Shorten the lifetime of the ephemeral reference to `'b`
+    /// according to [`Rebind`], without cloning anything.
+    ///
+    /// This is mostly needed in generic code, where the lifetime
+    /// cannot be shortened implicitly:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let greeting: Str<'static> = Str::from_ref("Hello");
+    /// let local = String::from("World");
+    /// let both = [greeting.narrow(), Str::from_ref(&local)];
+    /// assert!(both[0].is_ephemeral());
+    /// ```
+    pub fn narrow<'b>(self) -> Eso<Rebound<'b, ME>, MS, MO>
+    where
+        ME: MaybeMap<<<ME as Maybe>::Inner as Rebind<'b>>::Rebound>,
+        ME::Inner: Rebind<'b> + 'b,
+    {
+        match self {
+            Eso::E(e) => Eso::E(e.map(Rebind::narrow)),
+            Eso::S(s) => Eso::S(s),
+            Eso::O(o) => Eso::O(o),
+        }
+    }
+
     /// Borrow an ephemeral reference or preserve a static/shared reference.
     /// If the [`Eso`] contains an owned value, borrow a reference to it.
     ///
@@ -286,7 +376,6 @@ impl<ME, MS, MO> Eso<ME, MS, MO> {
     /// assert_eq!(interned.get_ref::<&str>(), "Hello World");
     /// // TODO an example for failed interning
     /// ```
-
     pub fn intern_or_take(self) -> x::SO<ME, MS, MO>
     where
         ME: MTryInternRef<MS::Inner> + MTake<MO::Inner>,
@@ -0,0 +1,114 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::maybe::{Maybe, MaybeTryMap};
+
+use super::*;
+
+/// A container like [`Option`] or [`Result`] that can be moved
+/// out of an [`Eso`] with [`Eso::transpose`].
+///
+/// The container is split into either the [`Value`](Self::Value)
+/// it holds, or the [`Residual`](Self::Residual) that is left when it
+/// holds none, i.e. `()` for [`Option`] and the error for [`Result`].
+pub trait Transpose {
+    /// The contained value
+    type Value;
+
+    /// What is left of the container when there is no value
+    type Residual;
+
+    /// Split the container into its value or residual
+    fn into_result(self) -> Result<Self::Value, Self::Residual>;
+}
+
+/// A type-level function that puts a new value of type `T` into
+/// the same kind of container as `Self`, see [`Eso::transpose`].
+pub trait Rewrap<T>: Transpose {
+    /// The container with the value replaced by a `T`
+    type Out;
+
+    /// Rebuild the container from a value or residual
+    fn rewrap(result: Result<T, Self::Residual>) -> Self::Out;
+}
+
+impl<A> Transpose for Option<A> {
+    type Value = A;
+    type Residual = ();
+
+    fn into_result(self) -> Result<A, ()> {
+        self.ok_or(())
+    }
+}
+
+impl<A, T> Rewrap<T> for Option<A> {
+    type Out = Option<T>;
+
+    fn rewrap(result: Result<T, ()>) -> Option<T> {
+        result.ok()
+    }
+}
+
+impl<A, Err> Transpose for Result<A, Err> {
+    type Value = A;
+    type Residual = Err;
+
+    fn into_result(self) -> Self {
+        self
+    }
+}
+
+impl<A, Err, T> Rewrap<T> for Result<A, Err> {
+    type Out = Result<T, Err>;
+
+    fn rewrap(result: Result<T, Err>) -> Result<T, Err> {
+        result
+    }
+}
+
+impl<ME: Maybe, MS: Maybe, MO: Maybe> Eso<ME, MS, MO> {
+    /// Move an [`Option`] or [`Result`] held by all variants of `self`
+    /// to the outside, keeping the variant structure:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Lookup<'a> = t::ESO<Option<&'a str>, Option<&'static str>, Option<String>>;
+    /// let found = Lookup::from_ref(Some("Hello")).transpose();
+    /// assert_eq!(found.unwrap().get_ref::<&str>(), "Hello");
+    ///
+    /// let missing = Lookup::from_static(None).transpose();
+    /// assert!(missing.is_none());
+    /// ```
+    ///
+    /// The variants that cannot exist in `self` still need to be
+    /// declared with the same container type:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Parsed = t::SO<Result<i32, String>, Result<i32, String>, Result<i64, String>>;
+    /// let parsed = Parsed::from_owned(Ok(42)).transpose();
+    /// assert_eq!(parsed.unwrap().get_owned(), Some(&42));
+    ///
+    /// let failed = Parsed::from_static(Err("nope".into())).transpose();
+    /// assert_eq!(failed.unwrap_err(), "nope");
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn transpose(self) -> <<ME as Maybe>::Inner as Rewrap<Eso<ME::Out, MS::Out, MO::Out>>>::Out
+    where
+        <ME as Maybe>::Inner: Rewrap<Eso<ME::Out, MS::Out, MO::Out>>,
+        <MS as Maybe>::Inner: Transpose<Residual = <<ME as Maybe>::Inner as Transpose>::Residual>,
+        <MO as Maybe>::Inner: Transpose<Residual = <<ME as Maybe>::Inner as Transpose>::Residual>,
+        ME: MaybeTryMap<<<ME as Maybe>::Inner as Transpose>::Value>,
+        MS: MaybeTryMap<<<MS as Maybe>::Inner as Transpose>::Value>,
+        MO: MaybeTryMap<<<MO as Maybe>::Inner as Transpose>::Value>,
+    {
+        <<ME as Maybe>::Inner as Rewrap<_>>::rewrap(self.try_map(
+            Transpose::into_result,
+            Transpose::into_result,
+            Transpose::into_result,
+        ))
+    }
+}
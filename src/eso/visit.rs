@@ -0,0 +1,123 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::maybe::{Maybe, MaybeMap};
+
+use super::*;
+
+/// A visitor for the contents of an [`Eso`], see [`Eso::visit`].
+///
+/// Unlike the three closures given to [`Eso::merge_with`], all
+/// three functions are given the same mutable context, so they can
+/// share state like a buffer or a counter.
+///
+/// The functions for variants that cannot exist in the visited
+/// [`Eso`] will never be called, but still need to be implemented,
+/// e.g. with [`unreachable!`].
+pub trait Visitor<E, S, O, Ctx: ?Sized> {
+    /// The result of the visit
+    type Output;
+
+    /// Visit the ephemeral value
+    fn visit_e(self, e: E, ctx: &mut Ctx) -> Self::Output;
+
+    /// Visit the static or shared value
+    fn visit_s(self, s: S, ctx: &mut Ctx) -> Self::Output;
+
+    /// Visit the owned value
+    fn visit_o(self, o: O, ctx: &mut Ctx) -> Self::Output;
+}
+
+impl<ME: Maybe, MS: Maybe, MO: Maybe> Eso<ME, MS, MO> {
+    /// Run the function of the [`Visitor`] that matches the
+    /// variant of `self`, giving it mutable access to `ctx`.
+    ///
+    /// ```
+    /// # use eso::{eso::Visitor, shorthand::t};
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    ///
+    /// struct Append;
+    ///
+    /// impl<'a> Visitor<&'a str, &'static str, String, String> for Append {
+    ///     type Output = bool;
+    ///
+    ///     fn visit_e(self, e: &'a str, buf: &mut String) -> bool {
+    ///         buf.push_str(e);
+    ///         false
+    ///     }
+    ///
+    ///     fn visit_s(self, s: &'static str, buf: &mut String) -> bool {
+    ///         buf.push_str(s);
+    ///         false
+    ///     }
+    ///
+    ///     fn visit_o(self, o: String, buf: &mut String) -> bool {
+    ///         buf.push_str(&o);
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let mut buf = String::new();
+    /// let words = vec![Str::from_ref("Hello"), Str::from_static(" "), Str::from_owned("World".into())];
+    /// let owned: Vec<bool> = words.into_iter().map(|w| w.visit(Append, &mut buf)).collect();
+    /// assert_eq!(buf, "Hello World");
+    /// assert_eq!(owned, [false, false, true]);
+    /// ```
+    pub fn visit<V, Ctx>(self, visitor: V, ctx: &mut Ctx) -> V::Output
+    where
+        V: Visitor<ME::Inner, MS::Inner, MO::Inner, Ctx>,
+        Ctx: ?Sized,
+    {
+        match self {
+            Eso::E(e) => visitor.visit_e(e.unwrap(), ctx),
+            Eso::S(s) => visitor.visit_s(s.unwrap(), ctx),
+            Eso::O(o) => visitor.visit_o(o.unwrap(), ctx),
+        }
+    }
+
+    /// Transform `self` by applying a different function to each of
+    /// the variants, as [`map`](Eso::map) does, but pass the shared
+    /// mutable context `ctx` to the function.
+    ///
+    /// Since the functions do not need to capture `ctx`, the borrow
+    /// checker accepts them even if all of them modify it:
+    ///
+    /// ```
+    /// # use eso::shorthand::t;
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let mut lengths = Vec::new();
+    /// let mapped = Str::from_ref("Hello").map_with(
+    ///     &mut lengths,
+    ///     |e, lengths| { lengths.push(e.len()); e },
+    ///     |s, lengths| { lengths.push(s.len()); s },
+    ///     |o, lengths| { lengths.push(o.len()); o.to_uppercase() },
+    /// );
+    /// assert!(mapped.is_ephemeral());
+    /// assert_eq!(lengths, [5]);
+    /// ```
+    pub fn map_with<Ctx, EF, ET, SF, ST, OF, OT>(
+        self,
+        ctx: &mut Ctx,
+        ef: EF,
+        sf: SF,
+        of: OF,
+    ) -> Eso<ME::Out, MS::Out, MO::Out>
+    where
+        Ctx: ?Sized,
+        ME: MaybeMap<ET>,
+        MS: MaybeMap<ST>,
+        MO: MaybeMap<OT>,
+        EF: FnOnce(ME::Inner, &mut Ctx) -> ET,
+        SF: FnOnce(MS::Inner, &mut Ctx) -> ST,
+        OF: FnOnce(MO::Inner, &mut Ctx) -> OT,
+    {
+        match self {
+            Eso::E(e) => Eso::E(e.map(|e| ef(e, ctx))),
+            Eso::S(s) => Eso::S(s.map(|s| sf(s, ctx))),
+            Eso::O(o) => Eso::O(o.map(|o| of(o, ctx))),
+        }
+    }
+}
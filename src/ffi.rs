@@ -0,0 +1,229 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A ready-made [`Eso`](crate::eso::Eso)-based container for C strings.
+//!
+//! [`EsoCStr`] is built on the [`Borrow`](crate::borrow::Borrow)
+//! and [`Take`](crate::borrow::Take) implementations for [`CStr`]
+//! and [`CString`].
+//! Rust strings and byte slices that already carry their terminating
+//! NUL are borrowed as they are, and only strings that lack it are
+//! copied into a freshly allocated [`CString`].
+
+use std::{
+    borrow::Cow,
+    ffi::{CStr, CString, NulError},
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::shorthand::t;
+
+/// The [`Eso`](crate::eso::Eso) type wrapped by [`EsoCStr`]
+pub type CStrEso<'a> = t::ESO<&'a CStr, &'static CStr, CString>;
+
+/// A C string that is either borrowed, static or owned.
+///
+/// ```
+/// # use eso::ffi::EsoCStr;
+/// let terminated = EsoCStr::from_str("Hello World\0").unwrap();
+/// assert!(terminated.as_eso().is_ephemeral());
+///
+/// let unterminated = EsoCStr::from_str("Hello World").unwrap();
+/// assert!(unterminated.as_eso().is_owning());
+///
+/// assert_eq!(terminated, unterminated);
+/// assert!(EsoCStr::from_str("Hello\0World").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EsoCStr<'a>(CStrEso<'a>);
+
+impl<'a> EsoCStr<'a> {
+    /// Create an [`EsoCStr`] borrowing the given C string
+    pub const fn from_c_str(s: &'a CStr) -> Self {
+        EsoCStr(CStrEso::from_ref(s))
+    }
+
+    /// Create an [`EsoCStr`] from a C string that lives forever
+    ///
+    /// ```
+    /// # use eso::ffi::EsoCStr; use std::ffi::CStr;
+    /// # let literal = CStr::from_bytes_with_nul(b"Hello World\0").unwrap();
+    /// let s = EsoCStr::from_static(literal);
+    /// assert!(s.as_eso().is_static());
+    /// ```
+    pub const fn from_static(s: &'static CStr) -> Self {
+        EsoCStr(CStrEso::from_static(s))
+    }
+
+    /// Create an [`EsoCStr`] taking ownership of the given C string
+    pub const fn from_c_string(s: CString) -> Self {
+        EsoCStr(CStrEso::from_owned(s))
+    }
+
+    /// Create an [`EsoCStr`] from a [`Cow`], keeping a borrowed
+    /// C string borrowed
+    pub fn from_cow(s: Cow<'a, CStr>) -> Self {
+        EsoCStr(CStrEso::from_cow(s))
+    }
+
+    /// Create an [`EsoCStr`] from a byte slice.
+    ///
+    /// If `bytes` ends in a NUL byte and contains no other NUL bytes,
+    /// it is borrowed. If it contains no NUL byte at all, it is copied
+    /// into an owned [`CString`] with a NUL byte appended.
+    /// Otherwise, the position of the offending NUL byte is reported
+    /// as an error.
+    ///
+    /// ```
+    /// # use eso::ffi::EsoCStr;
+    /// assert!(EsoCStr::from_bytes(b"abc\0").unwrap().as_eso().is_ephemeral());
+    /// assert!(EsoCStr::from_bytes(b"abc").unwrap().as_eso().is_owning());
+    /// assert_eq!(EsoCStr::from_bytes(b"a\0bc\0").unwrap_err().nul_position(), 1);
+    /// ```
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, NulError> {
+        match CStr::from_bytes_with_nul(bytes) {
+            Ok(s) => Ok(Self::from_c_str(s)),
+            Err(_) => CString::new(bytes).map(Self::from_c_string),
+        }
+    }
+
+    /// Create an [`EsoCStr`] from a string slice.
+    ///
+    /// See [`from_bytes`](EsoCStr::from_bytes) for the rules.
+    // `FromStr` cannot borrow from its input, so it is no substitute
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'a str) -> Result<Self, NulError> {
+        Self::from_bytes(s.as_bytes())
+    }
+
+    /// Create an [`EsoCStr`] from a byte slice that lives forever.
+    ///
+    /// Follows the same rules as [`from_bytes`](EsoCStr::from_bytes),
+    /// except that a NUL-terminated slice becomes a static reference.
+    ///
+    /// ```
+    /// # use eso::ffi::EsoCStr;
+    /// assert!(EsoCStr::from_static_bytes(b"abc\0").unwrap().as_eso().is_static());
+    /// assert!(EsoCStr::from_static_bytes(b"abc").unwrap().as_eso().is_owning());
+    /// ```
+    pub fn from_static_bytes(bytes: &'static [u8]) -> Result<Self, NulError> {
+        match CStr::from_bytes_with_nul(bytes) {
+            Ok(s) => Ok(Self::from_static(s)),
+            Err(_) => CString::new(bytes).map(Self::from_c_string),
+        }
+    }
+
+    /// Create an [`EsoCStr`] from a string slice that lives forever.
+    ///
+    /// See [`from_static_bytes`](EsoCStr::from_static_bytes) for the rules.
+    pub fn from_static_str(s: &'static str) -> Result<Self, NulError> {
+        Self::from_static_bytes(s.as_bytes())
+    }
+
+    /// Create an [`EsoCStr`] from an owned byte vector, reusing its
+    /// allocation for the [`CString`].
+    ///
+    /// The vector must not contain any NUL bytes.
+    pub fn from_vec(bytes: Vec<u8>) -> Result<Self, NulError> {
+        CString::new(bytes).map(Self::from_c_string)
+    }
+
+    /// Create an [`EsoCStr`] from an owned string, reusing its
+    /// allocation for the [`CString`].
+    ///
+    /// The string must not contain any NUL bytes.
+    pub fn from_string(s: String) -> Result<Self, NulError> {
+        Self::from_vec(s.into_bytes())
+    }
+
+    /// Access the underlying [`Eso`](crate::eso::Eso)
+    pub fn as_eso(&self) -> &CStrEso<'a> {
+        &self.0
+    }
+
+    /// Unwrap the underlying [`Eso`](crate::eso::Eso)
+    pub fn into_eso(self) -> CStrEso<'a> {
+        self.0
+    }
+
+    /// Borrow the contained C string
+    pub fn as_c_str(&self) -> &CStr {
+        self.0.get_ref()
+    }
+
+    /// Move the contained C string out, cloning it if it is not owned
+    pub fn into_c_string(self) -> CString {
+        self.0.into_owning().safe_unwrap_owned()
+    }
+
+    /// Shorten the lifetime of the C string, without cloning an
+    /// owned C string
+    pub fn narrow<'b>(self) -> EsoCStr<'b>
+    where
+        'a: 'b,
+    {
+        self
+    }
+
+    /// Clone a borrowed C string, so that the result is no longer tied
+    /// to any lifetime. A static C string is kept as it is.
+    pub fn into_static(self) -> EsoCStr<'static> {
+        EsoCStr(self.0.into_static().relax())
+    }
+
+    /// Clone any referenced C string, so that the result is
+    /// definitely owned
+    pub fn into_owning(self) -> EsoCStr<'static> {
+        EsoCStr(self.0.into_owning().relax())
+    }
+}
+
+impl<'a> Deref for EsoCStr<'a> {
+    type Target = CStr;
+
+    fn deref(&self) -> &CStr {
+        self.0.get_ref()
+    }
+}
+
+impl AsRef<CStr> for EsoCStr<'_> {
+    fn as_ref(&self) -> &CStr {
+        self.0.get_ref()
+    }
+}
+
+impl<'a> From<&'a CStr> for EsoCStr<'a> {
+    fn from(s: &'a CStr) -> Self {
+        Self::from_c_str(s)
+    }
+}
+
+impl From<CString> for EsoCStr<'_> {
+    fn from(s: CString) -> Self {
+        Self::from_c_string(s)
+    }
+}
+
+impl<'a> From<Cow<'a, CStr>> for EsoCStr<'a> {
+    fn from(s: Cow<'a, CStr>) -> Self {
+        Self::from_cow(s)
+    }
+}
+
+impl PartialEq for EsoCStr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_c_str() == other.as_c_str()
+    }
+}
+
+impl Eq for EsoCStr<'_> {}
+
+impl Hash for EsoCStr<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_c_str().hash(state)
+    }
+}
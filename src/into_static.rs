@@ -0,0 +1,343 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! The [`IntoStatic`] trait converts whole data structures containing
+//! [`Eso`]s into their `'static` form in one go.
+//!
+//! [`Eso::into_static`] followed by [`relax`](Eso::relax) only converts
+//! a single value. [`IntoStatic`] applies this conversion to every
+//! [`Eso`] nested inside the standard library containers:
+//!
+//! ```
+//! # use eso::{into_static::IntoStatic, shorthand::t}; use std::collections::HashMap;
+//! type Str<'a> = t::ESO<&'a str, &'static str, String>;
+//! type Doc<'a> = Vec<(Str<'a>, Option<Box<Str<'a>>>, HashMap<String, Str<'a>>)>;
+//!
+//! fn parse(input: &str) -> Doc {
+//!     input
+//!         .lines()
+//!         .map(|line| (Str::from_ref(line), None, HashMap::new()))
+//!         .collect()
+//! }
+//!
+//! let input = String::from("Hello\nWorld");
+//! let doc: Doc<'static> = parse(&input).into_static();
+//! drop(input);
+//! assert_eq!(doc[1].0.get_ref::<&str>(), "World");
+//! ```
+//!
+//! The `'static` form of an [`Eso`] keeps its shape, so the type of
+//! the ephemeral reference needs to say what it looks like with a
+//! `'static` lifetime. This is described by the [`StaticForm`] trait.
+//!
+//! With the `derive` feature, `#[derive(IntoStatic)]` implements the
+//! trait for your own structs and enums.
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, VecDeque},
+    ffi::{CString, OsString},
+    hash::{BuildHasher, Hash},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::{
+    borrow::Rebind,
+    eso::{req::MTake, Eso},
+    ffi::EsoCStr,
+    map::{EsoKey, EsoMap},
+    maybe::{An, Maybe, MaybeMap, No, Relax},
+    path::{EsoOsStr, EsoPath},
+    slice::EsoSlice,
+};
+
+/// A value that can be converted into a form that is not bound
+/// by any lifetime, by cloning ephemeral references where necessary.
+///
+/// Note that [`Eso`] has an inherent [`into_static`](Eso::into_static)
+/// method, which is preferred by method call syntax. Call this
+/// trait's function as `IntoStatic::into_static(eso)` if you need the
+/// `'static` form of a single [`Eso`].
+pub trait IntoStatic {
+    /// The type of `Self` with all lifetimes replaced by `'static`
+    type Static: 'static;
+
+    /// Convert into the `'static` form
+    fn into_static(self) -> Self::Static;
+}
+
+#[cfg(feature = "derive")]
+#[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "derive")))]
+pub use eso_derive::IntoStatic;
+
+/// A type-level function giving the type of an ephemeral reference
+/// with its lifetime replaced by `'static`.
+///
+/// This is used to name the `E` type of the [`IntoStatic::Static`]
+/// form of an [`Eso`]. It is implemented for every type that can be
+/// [`Rebind`]ed to `'static`.
+pub trait StaticForm {
+    /// The type of `Self` with its lifetime replaced by `'static`
+    type Static: 'static;
+}
+
+impl<T: Rebind<'static>> StaticForm for T
+where
+    T::Rebound: 'static,
+{
+    type Static = T::Rebound;
+}
+
+/// The `'static` form of an [`Eso`] has the same type parameters,
+/// except that the lifetime of the `E` type is replaced according to
+/// [`StaticForm`].
+///
+/// The shared and owned types must already be `'static`.
+impl<ME, MS, O> IntoStatic for Eso<ME, MS, An<O>>
+where
+    ME: MTake<O> + MaybeMap<<<ME as Maybe>::Inner as StaticForm>::Static>,
+    ME::Inner: StaticForm,
+    ME::Out: 'static,
+    No<ME::Inner>: Relax<ME::Out>,
+    MS: Relax<MS> + 'static,
+    O: 'static,
+{
+    type Static = Eso<ME::Out, MS, An<O>>;
+
+    fn into_static(self) -> Self::Static {
+        Eso::into_static(self).relax()
+    }
+}
+
+impl<T: IntoStatic> IntoStatic for Vec<T> {
+    type Static = Vec<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.into_iter().map(T::into_static).collect()
+    }
+}
+
+impl<T: IntoStatic> IntoStatic for VecDeque<T> {
+    type Static = VecDeque<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.into_iter().map(T::into_static).collect()
+    }
+}
+
+impl<T: IntoStatic> IntoStatic for Option<T> {
+    type Static = Option<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.map(T::into_static)
+    }
+}
+
+impl<T: IntoStatic, E: IntoStatic> IntoStatic for Result<T, E> {
+    type Static = Result<T::Static, E::Static>;
+
+    fn into_static(self) -> Self::Static {
+        match self {
+            Ok(t) => Ok(t.into_static()),
+            Err(e) => Err(e.into_static()),
+        }
+    }
+}
+
+impl<T: IntoStatic, const N: usize> IntoStatic for [T; N] {
+    type Static = [T::Static; N];
+
+    fn into_static(self) -> Self::Static {
+        self.map(T::into_static)
+    }
+}
+
+impl<T: IntoStatic> IntoStatic for Box<T> {
+    type Static = Box<T::Static>;
+
+    fn into_static(self) -> Self::Static {
+        Box::new((*self).into_static())
+    }
+}
+
+impl<K, V, S> IntoStatic for HashMap<K, V, S>
+where
+    K: IntoStatic,
+    K::Static: Eq + Hash,
+    V: IntoStatic,
+    S: BuildHasher + Default + 'static,
+{
+    type Static = HashMap<K::Static, V::Static, S>;
+
+    fn into_static(self) -> Self::Static {
+        self.into_iter()
+            .map(|(k, v)| (k.into_static(), v.into_static()))
+            .collect()
+    }
+}
+
+impl<K, V> IntoStatic for BTreeMap<K, V>
+where
+    K: IntoStatic,
+    K::Static: Ord,
+    V: IntoStatic,
+{
+    type Static = BTreeMap<K::Static, V::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.into_iter()
+            .map(|(k, v)| (k.into_static(), v.into_static()))
+            .collect()
+    }
+}
+
+impl<T: ?Sized + ToOwned + 'static> IntoStatic for Cow<'_, T> {
+    type Static = Cow<'static, T>;
+
+    fn into_static(self) -> Self::Static {
+        Cow::Owned(self.into_owned())
+    }
+}
+
+impl<T: ?Sized + 'static> IntoStatic for Arc<T> {
+    type Static = Arc<T>;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+}
+
+impl<T: ?Sized + 'static> IntoStatic for Rc<T> {
+    type Static = Rc<T>;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+}
+
+impl<T: ?Sized + 'static> IntoStatic for &'static T {
+    type Static = &'static T;
+
+    fn into_static(self) -> Self::Static {
+        self
+    }
+}
+
+macro_rules! impl_into_static_for_tuples {
+    ($( ( $($name:ident)+ ) )+) => {
+        $(
+            impl<$($name: IntoStatic),+> IntoStatic for ($($name,)+) {
+                type Static = ($($name::Static,)+);
+
+                #[allow(non_snake_case)]
+                fn into_static(self) -> Self::Static {
+                    let ($($name,)+) = self;
+                    ($($name.into_static(),)+)
+                }
+            }
+        )+
+    };
+}
+
+impl_into_static_for_tuples! {
+    (A)
+    (A B)
+    (A B C)
+    (A B C D)
+    (A B C D E)
+    (A B C D E F)
+    (A B C D E F G)
+    (A B C D E F G H)
+}
+
+macro_rules! impl_into_static_for_lasting {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl IntoStatic for $t {
+                type Static = $t;
+
+                #[inline]
+                fn into_static(self) -> Self::Static {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_into_static_for_lasting!(
+    (),
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    String,
+    PathBuf,
+    OsString,
+    CString,
+);
+
+impl IntoStatic for EsoPath<'_> {
+    type Static = EsoPath<'static>;
+
+    fn into_static(self) -> Self::Static {
+        EsoPath::into_static(self)
+    }
+}
+
+impl IntoStatic for EsoOsStr<'_> {
+    type Static = EsoOsStr<'static>;
+
+    fn into_static(self) -> Self::Static {
+        EsoOsStr::into_static(self)
+    }
+}
+
+impl IntoStatic for EsoCStr<'_> {
+    type Static = EsoCStr<'static>;
+
+    fn into_static(self) -> Self::Static {
+        EsoCStr::into_static(self)
+    }
+}
+
+impl<T: Clone + 'static> IntoStatic for EsoSlice<'_, T> {
+    type Static = EsoSlice<'static, T>;
+
+    fn into_static(self) -> Self::Static {
+        EsoSlice::into_static(self)
+    }
+}
+
+impl<Q, K, V> IntoStatic for EsoMap<Q, K, V>
+where
+    Q: ?Sized + Eq + Hash + 'static,
+    K: IntoStatic,
+    K::Static: EsoKey<Q>,
+    V: IntoStatic,
+{
+    type Static = EsoMap<Q, K::Static, V::Static>;
+
+    fn into_static(self) -> Self::Static {
+        self.into_iter()
+            .map(|(k, v)| (k.into_static(), v.into_static()))
+            .collect()
+    }
+}
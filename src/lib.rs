@@ -31,6 +31,20 @@
 //! the different operations that are required to use generalized
 //! references.
 //!
+//! The [`path`], [`ffi`] and [`slice`](mod@slice) modules contain ready-made
+//! containers built on [`Eso`] for the standard library path, C string
+//! and slice types, and the [`map`] module contains a hash map keyed
+//! by [`Eso`]s.
+//!
+//! The [`eso!`] macro creates an [`Eso`] with the variant that matches
+//! its arguments, e.g. a static string for a string literal.
+//!
+//! The [`into_static`] module converts whole data structures
+//! containing [`Eso`]s into their `'static` form.
+//!
+//! The [`proof`] module contains tokens that keep the shape of an
+//! [`Eso`] proven by a match, e.g. that it is not ephemeral.
+//!
 //! ## Feature flags
 //!
 //! ### `allow-unsafe`: Allow usage of `unsafe` Rust
@@ -51,6 +65,14 @@
 //! version = "0.0.3-active.*"
 //! default-features = false
 //! ```
+//!
+//! ### `derive`: Derive macros
+//!
+//! This feature is not active by default.
+//!
+//! It re-exports the derive macros from the `eso-derive` crate:
+//! [`IntoStatic`](crate::into_static::IntoStatic) and `EsoNewtype`,
+//! which generates the boilerplate for newtypes around an [`Eso`].
 
 #![deny(
     missing_docs,
@@ -68,11 +90,26 @@
 #[cfg_attr(feature = "unstable-doc-cfg", feature(doc_cfg))]
 pub mod borrow;
 pub mod eso;
+pub mod ffi;
+pub mod into_static;
+mod macros;
+pub mod map;
 pub mod maybe;
+pub mod path;
+pub mod proof;
 pub mod shorthand;
+pub mod slice;
 pub mod unify;
+pub mod zip;
 
 #[doc(inline)]
 pub use crate::eso::Eso;
 #[doc(inline)]
 pub use crate::maybe::{An, No};
+
+#[cfg(feature = "derive")]
+#[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "derive")))]
+pub use eso_derive::EsoNewtype;
+
+#[doc(hidden)]
+pub use crate::macros::__private;
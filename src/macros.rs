@@ -0,0 +1,156 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Create an [`Eso`](crate::eso::Eso), picking the variant from
+/// the syntax:
+///
+/// - `eso!("literal")` or `eso!(concat!(..))` creates an `S` holding the
+///   `&'static str`, using [`from_static`](crate::eso::Eso::from_static).
+///   This also works in `const` context.
+/// - `eso!(&expr)` creates an `E` borrowing `expr`, using
+///   [`from_ref`](crate::eso::Eso::from_ref).
+/// - `eso!(fmt, args..)` creates an `O` holding the [`String`] produced
+///   by [`format!`], using [`from_owned`](crate::eso::Eso::from_owned).
+///
+/// ```
+/// # use ::eso::{eso, shorthand::t};
+/// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+///
+/// const GREETING: Str = eso!(concat!("Hello", " ", "World"));
+/// assert!(GREETING.is_static());
+///
+/// let name = String::from("World");
+/// let borrowed: Str = eso!(&*name);
+/// assert!(borrowed.is_ephemeral());
+///
+/// let formatted: Str = eso!("Hello {}", name);
+/// assert!(formatted.is_owning());
+/// assert_eq!(formatted.get_ref::<&str>(), GREETING.get_ref::<&str>());
+/// ```
+///
+/// A literal that interpolates captured variables, or that contains
+/// escaped braces, needs to be formatted, and creates an `O`:
+///
+/// ```
+/// # use ::eso::{eso, shorthand::t};
+/// # type Str<'a> = t::ESO<&'a str, &'static str, String>;
+/// let name = "World";
+/// let formatted: Str = eso!("Hello {name}");
+/// assert!(formatted.is_owning());
+/// assert_eq!(formatted.get_ref::<&str>(), "Hello World");
+///
+/// let escaped: Str = eso!("{{}}");
+/// assert_eq!(escaped.get_ref::<&str>(), "{}");
+/// ```
+#[macro_export]
+macro_rules! eso {
+    ($lit:literal) => {
+        $crate::eso!(@literal $lit)
+    };
+    (concat!($($parts:tt)*)) => {
+        $crate::eso!(@literal concat!($($parts)*))
+    };
+    (& $e:expr) => {
+        $crate::eso::Eso::from_ref(&$e)
+    };
+    ($fmt:literal, $($args:tt)*) => {
+        $crate::eso::Eso::from_owned(::std::format!($fmt, $($args)*))
+    };
+    (concat!($($parts:tt)*), $($args:tt)*) => {
+        $crate::eso::Eso::from_owned(::std::format!(concat!($($parts)*), $($args)*))
+    };
+    (@literal $lit:expr) => {{
+        const __ESO_LITERAL: &::std::primitive::str = $lit;
+        $crate::__private::Literal::<{ $crate::__private::has_braces(__ESO_LITERAL) }>::make(
+            __ESO_LITERAL,
+            || ::std::format!($lit),
+        )
+    }};
+}
+
+/// Implementation details of the [`eso!`] macro and of the derive
+/// macros
+pub mod __private {
+    use crate::{
+        eso::{req::MTake, Eso},
+        maybe::{An, Maybe, Relax},
+        shorthand::x,
+    };
+
+    /// Whether a literal needs to be passed through [`format!`]
+    pub const fn has_braces(s: &str) -> bool {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'{' || bytes[i] == b'}' {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Selects between a static and a formatted literal
+    /// depending on [`has_braces`]
+    #[derive(Debug, Clone, Copy)]
+    pub struct Literal<const FORMAT: bool>;
+
+    impl Literal<false> {
+        /// Use the literal as it is
+        pub const fn make<ME, MO, F>(s: &'static str, format: F) -> Eso<ME, An<&'static str>, MO> {
+            std::mem::forget(format);
+            Eso::from_static(s)
+        }
+    }
+
+    impl Literal<true> {
+        /// Format the literal
+        pub fn make<ME, MS, F>(_: &'static str, format: F) -> Eso<ME, MS, An<String>>
+        where
+            F: FnOnce() -> String,
+        {
+            Eso::from_owned(format())
+        }
+    }
+
+    /// [`Eso::into_static`] followed by [`Relax::relax`], as a trait
+    /// so that `#[derive(EsoNewtype)]` can put it into a `where` clause
+    /// when it cannot read the type parameters of the field
+    pub trait IntoStaticAs<T> {
+        /// Convert and relax into `T`
+        fn into_static_as(self) -> T;
+    }
+
+    impl<ME, MS, MO, T> IntoStaticAs<T> for Eso<ME, MS, MO>
+    where
+        ME: MTake<MO::Inner>,
+        MO: Maybe,
+        x::sO<ME, MS, MO>: Relax<T>,
+    {
+        fn into_static_as(self) -> T {
+            Relax::relax(self.into_static())
+        }
+    }
+
+    /// [`Eso::into_owning`] followed by [`Relax::relax`], see
+    /// [`IntoStaticAs`]
+    pub trait IntoOwningAs<T> {
+        /// Convert and relax into `T`
+        fn into_owning_as(self) -> T;
+    }
+
+    impl<ME, MS, MO, T> IntoOwningAs<T> for Eso<ME, MS, MO>
+    where
+        ME: MTake<MO::Inner>,
+        MS: MTake<MO::Inner>,
+        MO: Maybe,
+        x::O<ME, MS, MO>: Relax<T>,
+    {
+        fn into_owning_as(self) -> T {
+            Relax::relax(self.into_owning())
+        }
+    }
+}
@@ -0,0 +1,579 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A hash map keyed by [`Eso`]s.
+//!
+//! [`EsoMap`] looks up its entries by a plain reference of type `&Q`,
+//! e.g. a `&str` for keys of type `t::ESO<&'a str, Arc<str>, String>`.
+//! Its [`entry`](EsoMap::entry) API also takes the key by reference,
+//! and only turns it into an owned or interned key via
+//! [`Take::own`] or
+//! [`Eso::intern_or_take`] when a new entry is actually inserted.
+//!
+//! As a [`HashMap`] can only be entered with an owned key, this
+//! costs extra lookups: [`EsoMap::entry`] looks the key up once, and
+//! every access to the entry it returns looks it up again.
+
+use std::{
+    collections::{hash_map, HashMap},
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+    marker::PhantomData,
+};
+
+use crate::{
+    borrow::{Take, TryInternRef},
+    eso::{req::MTake, Eso},
+    maybe::{An, Maybe, No, Relax},
+};
+
+/// An [`Eso`] that can be viewed as a `&Q` to be looked up
+/// in an [`EsoMap`].
+///
+/// This is implemented for all [`Eso`]s whose inner types are
+/// [`std::borrow::Borrow<Q>`], which guarantees that hashing and
+/// comparing the key gives the same results for all variants.
+pub trait EsoKey<Q: ?Sized> {
+    /// View the key as a `&Q`
+    fn key(&self) -> &Q;
+}
+
+impl<Q: ?Sized, ME, MS, MO> EsoKey<Q> for Eso<ME, MS, MO>
+where
+    ME: Maybe,
+    MS: Maybe,
+    MO: Maybe,
+    ME::Inner: std::borrow::Borrow<Q>,
+    MS::Inner: std::borrow::Borrow<Q>,
+    MO::Inner: std::borrow::Borrow<Q>,
+{
+    fn key(&self) -> &Q {
+        use std::borrow::Borrow;
+        match self {
+            Eso::E(e) => e.inner().borrow(),
+            Eso::S(s) => s.inner().borrow(),
+            Eso::O(o) => o.inner().borrow(),
+        }
+    }
+}
+
+/// Wrapper to hash and compare a key by its `&Q` view, so that
+/// the underlying [`HashMap`] can be queried with a `&Q`.
+#[repr(transparent)]
+struct Key<Q: ?Sized, K>(K, PhantomData<fn() -> *const Q>);
+
+impl<Q: ?Sized, K> Key<Q, K> {
+    fn new(k: K) -> Self {
+        Key(k, PhantomData)
+    }
+}
+
+impl<Q: ?Sized + Hash, K: EsoKey<Q>> Hash for Key<Q, K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.key().hash(state)
+    }
+}
+
+impl<Q: ?Sized + PartialEq, K: EsoKey<Q>> PartialEq for Key<Q, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.key() == other.0.key()
+    }
+}
+
+impl<Q: ?Sized + Eq, K: EsoKey<Q>> Eq for Key<Q, K> {}
+
+impl<Q: ?Sized, K: EsoKey<Q>> std::borrow::Borrow<Q> for Key<Q, K> {
+    fn borrow(&self) -> &Q {
+        self.0.key()
+    }
+}
+
+impl<Q: ?Sized, K: Clone> Clone for Key<Q, K> {
+    fn clone(&self) -> Self {
+        Key::new(self.0.clone())
+    }
+}
+
+impl<Q: ?Sized, K: fmt::Debug> fmt::Debug for Key<Q, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A hash map with [`Eso`] keys of type `K` that are looked up by a `&Q`.
+///
+/// ```
+/// # use eso::{map::EsoMap, shorthand::t}; use std::sync::Arc;
+/// type Symbol<'a> = t::ESO<&'a str, Arc<str>, String>;
+/// let mut symbols: EsoMap<str, Symbol, usize> = EsoMap::new();
+///
+/// let input = String::from("x y x");
+/// for word in input.split(' ') {
+///     *symbols.entry(word).or_insert_interned(0) += 1;
+/// }
+/// assert_eq!(symbols.get("x"), Some(&2));
+/// assert!(symbols.keys().all(|k| k.is_static()));
+/// ```
+pub struct EsoMap<Q: ?Sized, K, V> {
+    map: HashMap<Key<Q, K>, V>,
+}
+
+impl<Q: ?Sized, K, V> EsoMap<Q, K, V> {
+    /// Create an empty map
+    pub fn new() -> Self {
+        EsoMap {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Create an empty map with room for at least `capacity` entries
+    pub fn with_capacity(capacity: usize) -> Self {
+        EsoMap {
+            map: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// The number of entries in the map
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no entries
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Remove all entries
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Iterate over all entries in arbitrary order
+    pub fn iter(&self) -> Iter<'_, Q, K, V> {
+        Iter(self.map.iter())
+    }
+
+    /// Iterate over all keys in arbitrary order
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.map.keys().map(|k| &k.0)
+    }
+
+    /// Iterate over all values in arbitrary order
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.map.values()
+    }
+
+    /// Iterate mutably over all values in arbitrary order
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.map.values_mut()
+    }
+}
+
+impl<Q, K, V> EsoMap<Q, K, V>
+where
+    Q: ?Sized + Hash + Eq,
+    K: EsoKey<Q>,
+{
+    /// Look up the value for `key`
+    pub fn get(&self, key: &Q) -> Option<&V> {
+        self.map.get(key)
+    }
+
+    /// Look up the value for `key` for modification
+    pub fn get_mut(&mut self, key: &Q) -> Option<&mut V> {
+        self.map.get_mut(key)
+    }
+
+    /// Look up the stored key and the value for `key`
+    pub fn get_key_value(&self, key: &Q) -> Option<(&K, &V)> {
+        self.map.get_key_value(key).map(|(k, v)| (&k.0, v))
+    }
+
+    /// Returns `true` if the map contains an entry for `key`
+    pub fn contains_key(&self, key: &Q) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Insert a value under the given key, returning the previous value.
+    ///
+    /// As with [`HashMap::insert`], the stored key is not replaced
+    /// if an entry for it exists already.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.map.insert(Key::new(key), value)
+    }
+
+    /// Remove the entry for `key`, returning its value
+    pub fn remove(&mut self, key: &Q) -> Option<V> {
+        self.map.remove(key)
+    }
+
+    /// Remove the entry for `key`, returning the stored key and value
+    pub fn remove_entry(&mut self, key: &Q) -> Option<(K, V)> {
+        self.map.remove_entry(key).map(|(k, v)| (k.0, v))
+    }
+
+    /// Get the entry for `key` for in-place manipulation.
+    ///
+    /// The key is only converted into a `K` if a new entry is inserted.
+    ///
+    /// Unlike [`HashMap::entry`], this looks up `key` only to decide
+    /// whether the entry is occupied. The methods of [`OccupiedEntry`]
+    /// and the insertion into a [`VacantEntry`] look it up again, so
+    /// e.g. [`or_insert`](Entry::or_insert) takes two lookups. Where
+    /// that matters and the key is likely present, try
+    /// [`get_mut`](Self::get_mut) first.
+    pub fn entry<'m, 'q>(&'m mut self, key: &'q Q) -> Entry<'m, 'q, Q, K, V> {
+        if self.map.contains_key(key) {
+            Entry::Occupied(OccupiedEntry {
+                map: &mut self.map,
+                key,
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                map: &mut self.map,
+                key,
+            })
+        }
+    }
+}
+
+impl<Q, ME, MS, MO, V> EsoMap<Q, Eso<ME, MS, MO>, V>
+where
+    Q: ?Sized + Hash + Eq,
+{
+    /// Transform all keys via [`Eso::into_static`] and relax them
+    /// into the desired key type, e.g. to drop the lifetime of
+    /// ephemeral keys.
+    ///
+    /// ```
+    /// # use eso::{map::EsoMap, shorthand::t};
+    /// type Name<'a> = t::ESO<&'a str, &'static str, String>;
+    /// fn collect(input: &str) -> EsoMap<str, Name<'static>, usize> {
+    ///     let mut names: EsoMap<str, Name, usize> = EsoMap::new();
+    ///     for (i, name) in input.split(',').enumerate() {
+    ///         names.insert(Name::from_ref(name), i);
+    ///     }
+    ///     names.into_static()
+    /// }
+    /// let names = collect("a,b");
+    /// assert_eq!(names.get("b"), Some(&1));
+    /// ```
+    pub fn into_static<ME1, MS1, MO1>(self) -> EsoMap<Q, Eso<ME1, MS1, MO1>, V>
+    where
+        ME: MTake<MO::Inner>,
+        MO: Maybe,
+        No<ME::Inner>: Relax<ME1>,
+        MS: Relax<MS1>,
+        An<MO::Inner>: Relax<MO1>,
+        Eso<ME1, MS1, MO1>: EsoKey<Q>,
+    {
+        let mut map = HashMap::with_capacity(self.map.len());
+        for (k, v) in self.map {
+            map.insert(Key::new(k.0.into_static().relax()), v);
+        }
+        EsoMap { map }
+    }
+}
+
+impl<Q: ?Sized, K, V> Default for EsoMap<Q, K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Q: ?Sized, K: Clone, V: Clone> Clone for EsoMap<Q, K, V> {
+    fn clone(&self) -> Self {
+        EsoMap {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<Q: ?Sized, K: fmt::Debug, V: fmt::Debug> fmt::Debug for EsoMap<Q, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<Q, K, V> FromIterator<(K, V)> for EsoMap<Q, K, V>
+where
+    Q: ?Sized + Hash + Eq,
+    K: EsoKey<Q>,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = EsoMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<Q, K, V> Extend<(K, V)> for EsoMap<Q, K, V>
+where
+    Q: ?Sized + Hash + Eq,
+    K: EsoKey<Q>,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.map
+            .extend(iter.into_iter().map(|(k, v)| (Key::new(k), v)))
+    }
+}
+
+impl<Q: ?Sized, K, V> IntoIterator for EsoMap<Q, K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<Q, K, V>;
+
+    fn into_iter(self) -> IntoIter<Q, K, V> {
+        IntoIter(self.map.into_iter())
+    }
+}
+
+/// An iterator over the entries of an [`EsoMap`], see [`EsoMap::iter`]
+#[derive(Debug)]
+pub struct Iter<'m, Q: ?Sized, K, V>(hash_map::Iter<'m, Key<Q, K>, V>);
+
+impl<'m, Q: ?Sized, K, V> Iterator for Iter<'m, Q, K, V> {
+    type Item = (&'m K, &'m V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (&k.0, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// An owning iterator over the entries of an [`EsoMap`]
+#[derive(Debug)]
+pub struct IntoIter<Q: ?Sized, K, V>(hash_map::IntoIter<Key<Q, K>, V>);
+
+impl<Q: ?Sized, K, V> Iterator for IntoIter<Q, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k.0, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// A view into a single entry of an [`EsoMap`], see [`EsoMap::entry`]
+#[derive(Debug)]
+pub enum Entry<'m, 'q, Q: ?Sized, K, V> {
+    /// An entry for the key exists
+    Occupied(OccupiedEntry<'m, 'q, Q, K, V>),
+    /// There is no entry for the key
+    Vacant(VacantEntry<'m, 'q, Q, K, V>),
+}
+
+/// An existing entry of an [`EsoMap`].
+///
+/// It only keeps the key that was looked up, so each of its methods
+/// looks it up in the map again.
+#[derive(Debug)]
+pub struct OccupiedEntry<'m, 'q, Q: ?Sized, K, V> {
+    map: &'m mut HashMap<Key<Q, K>, V>,
+    key: &'q Q,
+}
+
+/// A missing entry of an [`EsoMap`].
+///
+/// Inserting into it looks up the key again.
+#[derive(Debug)]
+pub struct VacantEntry<'m, 'q, Q: ?Sized, K, V> {
+    map: &'m mut HashMap<Key<Q, K>, V>,
+    key: &'q Q,
+}
+
+impl<'m, 'q, Q, K, V> Entry<'m, 'q, Q, K, V>
+where
+    Q: ?Sized + Hash + Eq,
+    K: EsoKey<Q>,
+{
+    /// The key that was looked up
+    pub fn key(&self) -> &'q Q {
+        match self {
+            Entry::Occupied(o) => o.key,
+            Entry::Vacant(v) => v.key,
+        }
+    }
+
+    /// Modify the value of an occupied entry
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(o) = &mut self {
+            f(o.get_mut());
+        }
+        self
+    }
+}
+
+impl<'m, 'q, Q, ME, MS, O, V> Entry<'m, 'q, Q, Eso<ME, MS, An<O>>, V>
+where
+    Q: ?Sized + Hash + Eq,
+    Eso<ME, MS, An<O>>: EsoKey<Q>,
+    &'q Q: Take<O>,
+{
+    /// Get the value, inserting `value` if the entry is vacant.
+    /// See [`VacantEntry::insert`].
+    pub fn or_insert(self, value: V) -> &'m mut V {
+        self.or_insert_with(|| value)
+    }
+
+    /// Get the value, inserting the result of `f` if the entry is vacant.
+    /// See [`VacantEntry::insert`].
+    pub fn or_insert_with<F: FnOnce() -> V>(self, f: F) -> &'m mut V {
+        match self {
+            Entry::Occupied(o) => o.into_mut(),
+            Entry::Vacant(v) => v.insert(f()),
+        }
+    }
+
+    /// Get the value, inserting the default value if the entry is vacant.
+    /// See [`VacantEntry::insert`].
+    pub fn or_default(self) -> &'m mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'m, 'q, Q, ME, S, O, V> Entry<'m, 'q, Q, Eso<ME, An<S>, An<O>>, V>
+where
+    Q: ?Sized + Hash + Eq,
+    Eso<ME, An<S>, An<O>>: EsoKey<Q>,
+    &'q Q: TryInternRef<S> + Take<O>,
+    No<&'q Q>: Relax<ME>,
+{
+    /// Get the value, inserting `value` if the entry is vacant.
+    /// See [`VacantEntry::insert_interned`].
+    pub fn or_insert_interned(self, value: V) -> &'m mut V {
+        self.or_insert_interned_with(|| value)
+    }
+
+    /// Get the value, inserting the result of `f` if the entry is vacant.
+    /// See [`VacantEntry::insert_interned`].
+    pub fn or_insert_interned_with<F: FnOnce() -> V>(self, f: F) -> &'m mut V {
+        match self {
+            Entry::Occupied(o) => o.into_mut(),
+            Entry::Vacant(v) => v.insert_interned(f()),
+        }
+    }
+}
+
+impl<'m, 'q, Q, K, V> OccupiedEntry<'m, 'q, Q, K, V>
+where
+    Q: ?Sized + Hash + Eq,
+    K: EsoKey<Q>,
+{
+    /// The key stored in the map
+    pub fn key(&self) -> &K {
+        match self.map.get_key_value(self.key) {
+            Some((k, _)) => &k.0,
+            None => unreachable!(), // the entry is occupied
+        }
+    }
+
+    /// Reference the value of the entry
+    pub fn get(&self) -> &V {
+        match self.map.get(self.key) {
+            Some(v) => v,
+            None => unreachable!(), // the entry is occupied
+        }
+    }
+
+    /// Mutably reference the value of the entry
+    pub fn get_mut(&mut self) -> &mut V {
+        match self.map.get_mut(self.key) {
+            Some(v) => v,
+            None => unreachable!(), // the entry is occupied
+        }
+    }
+
+    /// Mutably reference the value with the lifetime of the map
+    pub fn into_mut(self) -> &'m mut V {
+        match self.map.get_mut(self.key) {
+            Some(v) => v,
+            None => unreachable!(), // the entry is occupied
+        }
+    }
+
+    /// Replace the value of the entry, returning the old one
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    /// Remove the entry, returning its value
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Remove the entry, returning the stored key and the value
+    pub fn remove_entry(self) -> (K, V) {
+        match self.map.remove_entry(self.key) {
+            Some((k, v)) => (k.0, v),
+            None => unreachable!(), // the entry is occupied
+        }
+    }
+}
+
+impl<'m, 'q, Q, K, V> VacantEntry<'m, 'q, Q, K, V>
+where
+    Q: ?Sized + Hash + Eq,
+    K: EsoKey<Q>,
+{
+    /// The key that was looked up
+    pub fn key(&self) -> &'q Q {
+        self.key
+    }
+
+    fn insert_key(self, key: K, value: V) -> &'m mut V {
+        self.map.entry(Key::new(key)).or_insert(value)
+    }
+}
+
+impl<'m, 'q, Q, ME, MS, O, V> VacantEntry<'m, 'q, Q, Eso<ME, MS, An<O>>, V>
+where
+    Q: ?Sized + Hash + Eq,
+    Eso<ME, MS, An<O>>: EsoKey<Q>,
+    &'q Q: Take<O>,
+{
+    /// Insert `value`, storing an owned key made via
+    /// [`Take::own`]
+    ///
+    /// ```
+    /// # use eso::{map::{EsoMap, Entry}, shorthand::t};
+    /// type Name<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let mut names: EsoMap<str, Name, i32> = EsoMap::new();
+    /// if let Entry::Vacant(v) = names.entry("x") {
+    ///     v.insert(1);
+    /// }
+    /// assert!(names.get_key_value("x").unwrap().0.is_owning());
+    /// ```
+    pub fn insert(self, value: V) -> &'m mut V {
+        let key = Eso::from_owned(self.key.own());
+        self.insert_key(key, value)
+    }
+}
+
+impl<'m, 'q, Q, ME, S, O, V> VacantEntry<'m, 'q, Q, Eso<ME, An<S>, An<O>>, V>
+where
+    Q: ?Sized + Hash + Eq,
+    Eso<ME, An<S>, An<O>>: EsoKey<Q>,
+    &'q Q: TryInternRef<S> + Take<O>,
+    No<&'q Q>: Relax<ME>,
+{
+    /// Insert `value`, storing a key made via [`Eso::intern_or_take`]
+    pub fn insert_interned(self, value: V) -> &'m mut V {
+        let key = Eso::<An<&'q Q>, An<S>, An<O>>::from_ref(self.key)
+            .intern_or_take()
+            .relax();
+        self.insert_key(key, value)
+    }
+}
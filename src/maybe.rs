@@ -6,8 +6,13 @@
 
 //! The [`Maybe`] trait and its two implementations [`An`] and [`No`]
 //! describe a compile-time optional value.
+//!
+//! The type-level functions [`And`], [`Or`] and [`Not`] combine the
+//! presence of [`Maybe`]s, and of the variants of [`Eso`](crate::eso::Eso)s.
+//! [`Normalize`] gives every [`No`] the same canonical type.
 
 use std::{
+    convert::Infallible,
     marker::PhantomData,
     panic::{RefUnwindSafe, UnwindSafe},
 };
@@ -57,6 +62,9 @@ pub trait Maybe: Sized + __SealMaybe {
     /// The type whose presence or absence is in question
     type Inner;
 
+    /// `true` for [`An`], `false` for [`No`]
+    const PRESENT: bool;
+
     /// Yield a reference to the inner value.
     fn inner(&self) -> &Self::Inner;
 
@@ -87,6 +95,74 @@ pub trait Maybe: Sized + __SealMaybe {
     {
         <Self as MaybeMap<NewInner>>::do_map(self, f)
     }
+
+    /// Apply a fallible function to the contained value, keeping
+    /// the result contained if it succeeds.
+    fn try_map<F, NewInner, Err>(self, f: F) -> Result<<Self as MaybeMap<NewInner>>::Out, Err>
+    where
+        Self: MaybeTryMap<NewInner>,
+        F: FnOnce(Self::Inner) -> Result<NewInner, Err>,
+    {
+        <Self as MaybeTryMap<NewInner>>::do_try_map(self, f)
+    }
+
+    /// Borrow the inner value, keeping it wrapped, as
+    /// [`Option::as_ref`] does.
+    ///
+    /// ```
+    /// # use eso::maybe::{An, Maybe};
+    /// let greeting = An(String::from("Hello"));
+    /// let borrowed: An<&String> = greeting.as_ref();
+    /// assert_eq!(borrowed.unwrap(), "Hello");
+    /// ```
+    fn as_ref<'a>(&'a self) -> <Self as MaybeAsRef<'a>>::Ref
+    where
+        Self: MaybeAsRef<'a>,
+        Self::Inner: 'a,
+    {
+        self.maybe_as_ref()
+    }
+
+    /// Mutably borrow the inner value, keeping it wrapped, as
+    /// [`Option::as_mut`] does.
+    ///
+    /// ```
+    /// # use eso::maybe::{An, Maybe};
+    /// let mut greeting = An(String::from("Hello"));
+    /// greeting.as_mut().unwrap().push_str(" World");
+    /// assert_eq!(greeting.unwrap(), "Hello World");
+    /// ```
+    fn as_mut<'a>(&'a mut self) -> <Self as MaybeAsMut<'a>>::Mut
+    where
+        Self: MaybeAsMut<'a>,
+        Self::Inner: 'a,
+    {
+        self.maybe_as_mut()
+    }
+
+    /// Combine `self` and `b` into a pair, which is only present
+    /// if both of them are, following the rules of [`And`].
+    ///
+    /// ```
+    /// # use eso::maybe::{An, Maybe};
+    /// let pair = An(1).zip(An("one"));
+    /// assert_eq!(pair.unwrap(), (1, "one"));
+    /// ```
+    fn zip<B: Maybe>(self, b: B) -> <Self as MaybeZip<B>>::Out
+    where
+        Self: MaybeZip<B>,
+    {
+        self.do_zip(b)
+    }
+
+    /// Convert into an [`Option`], which is always [`Some`] since
+    /// no [`No`] can exist.
+    ///
+    /// ```
+    /// # use eso::maybe::{An, Maybe};
+    /// assert_eq!(An(42).into_option(), Some(42));
+    /// ```
+    fn into_option(self) -> Option<Self::Inner>;
 }
 
 /// A type-level function to describe the result
@@ -126,6 +202,7 @@ impl<A> __SealMaybe for An<A> {}
 
 impl<A> Maybe for An<A> {
     type Inner = A;
+    const PRESENT: bool = true;
 
     fn inner(&self) -> &A {
         &self.0
@@ -166,6 +243,24 @@ impl<A> Maybe for An<A> {
     {
         <Self as MaybeMap<B>>::do_map(self, f)
     }
+
+    #[inline]
+    fn into_option(self) -> Option<A> {
+        Some(self.0)
+    }
+}
+
+impl<A> An<A> {
+    /// Take the value out of an [`Option`], if there is one.
+    ///
+    /// ```
+    /// # use eso::maybe::{An, Maybe};
+    /// assert_eq!(An::from_option(Some(42)).map(Maybe::unwrap), Some(42));
+    /// assert!(An::<i32>::from_option(None).is_none());
+    /// ```
+    pub fn from_option(value: Option<A>) -> Option<Self> {
+        value.map(An)
+    }
 }
 
 impl<A, B> MaybeMap<B> for An<A> {
@@ -202,6 +297,7 @@ impl<A> __SealMaybe for No<A> {}
 
 impl<A> Maybe for No<A> {
     type Inner = A;
+    const PRESENT: bool = false;
 
     fn inner(&self) -> &A {
         self.absurd()
@@ -236,6 +332,10 @@ impl<A> Maybe for No<A> {
     {
         self.absurd()
     }
+
+    fn into_option(self) -> Option<A> {
+        self.absurd()
+    }
 }
 
 impl<A, B> MaybeMap<B> for No<A> {
@@ -249,6 +349,382 @@ impl<A, B> MaybeMap<B> for No<A> {
     }
 }
 
+/// The fallible counterpart of [`MaybeMap`], describing the result
+/// of a [`Maybe::try_map`] operation
+pub trait MaybeTryMap<NewInner>: MaybeMap<NewInner> {
+    /// The `self` is required as evidence that
+    /// you are not constructing a [`No`].
+    fn do_try_map<F, Err>(self, f: F) -> Result<Self::Out, Err>
+    where
+        F: FnOnce(Self::Inner) -> Result<NewInner, Err>;
+}
+
+impl<A, B> MaybeTryMap<B> for An<A> {
+    #[inline]
+    fn do_try_map<F, Err>(self, f: F) -> Result<Self::Out, Err>
+    where
+        F: FnOnce(Self::Inner) -> Result<B, Err>,
+    {
+        f(self.0).map(An)
+    }
+}
+
+impl<A, B> MaybeTryMap<B> for No<A> {
+    fn do_try_map<F, Err>(self, _: F) -> Result<Self::Out, Err>
+    where
+        F: FnOnce(Self::Inner) -> Result<B, Err>,
+    {
+        self.absurd()
+    }
+}
+
+/// A type-level function to describe the result of combining two
+/// [`Maybe`]s into a pair, which only exists if both of them exist.
+pub trait MaybeZip<B: Maybe>: Maybe {
+    /// An [`An`] of the pair if both `Self` and `B` are [`An`],
+    /// a [`No`] otherwise
+    type Out: Maybe<Inner = (Self::Inner, B::Inner)>;
+
+    /// The `self` and `b` are required as evidence that
+    /// you are not constructing a [`No`].
+    fn do_zip(self, b: B) -> Self::Out;
+}
+
+impl<A, B> MaybeZip<An<B>> for An<A> {
+    type Out = An<(A, B)>;
+
+    #[inline]
+    fn do_zip(self, b: An<B>) -> Self::Out {
+        An((self.0, b.0))
+    }
+}
+
+impl<A, B> MaybeZip<No<B>> for An<A> {
+    type Out = No<(A, B)>;
+
+    fn do_zip(self, b: No<B>) -> Self::Out {
+        b.absurd()
+    }
+}
+
+impl<A, MB: Maybe> MaybeZip<MB> for No<A> {
+    type Out = No<(A, MB::Inner)>;
+
+    fn do_zip(self, _: MB) -> Self::Out {
+        self.absurd()
+    }
+}
+
+/// A type-level function that is [`An`] if both `Self` and `B` are
+/// [`An`], and [`No`] otherwise.
+///
+/// The result keeps the inner type of `Self`. An [`Eso`](crate::eso::Eso)
+/// applies the function to each of its type parameters, which gives
+/// the variants that both of two [`Eso`](crate::eso::Eso)s can have:
+///
+/// ```
+/// # use eso::{maybe::And, shorthand::t};
+/// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+/// type Lasting<'a> = t::SO<&'a str, &'static str, String>;
+/// type NotOwned<'a> = t::ES<&'a str, &'static str, String>;
+/// type Both<'a> = <Lasting<'a> as And<NotOwned<'a>>>::Out;
+/// let both: Both = Str::from_static("Hello").try_split_static().unwrap();
+/// assert_eq!(Both::SHAPE, (false, true, false));
+/// ```
+pub trait And<B> {
+    /// The conjunction of `Self` and `B`
+    type Out;
+}
+
+impl<A, B> And<An<B>> for An<A> {
+    type Out = An<A>;
+}
+
+impl<A, B> And<No<B>> for An<A> {
+    type Out = No<A>;
+}
+
+impl<A, MB: Maybe> And<MB> for No<A> {
+    type Out = No<A>;
+}
+
+/// A type-level function that is [`An`] if either `Self` or `B` is
+/// [`An`], and [`No`] otherwise.
+///
+/// The result keeps the inner type of the side that is [`An`], or of
+/// `Self` if both are. An [`Eso`](crate::eso::Eso) applies the function
+/// to each of its type parameters, which gives the variants that either
+/// of two [`Eso`](crate::eso::Eso)s can have:
+///
+/// ```
+/// # use eso::{maybe::Or, shorthand::t};
+/// type Ephemeral<'a> = t::E<&'a str, &'static str, String>;
+/// type Owned<'a> = t::O<&'a str, &'static str, String>;
+/// type Either<'a> = <Ephemeral<'a> as Or<Owned<'a>>>::Out;
+/// assert_eq!(Either::SHAPE, (true, false, true));
+/// let either: Either = Owned::from_owned("Hello".into()).relax();
+/// assert!(either.is_owning());
+/// ```
+///
+/// The inner types of the [`No`]s do not matter, so this also works
+/// on [`Normalize`]d types:
+///
+/// ```
+/// # use eso::{maybe::Or, shorthand::t};
+/// type Ephemeral<'a> = t::E<&'a str, (), ()>;
+/// type Owned<'a> = t::O<&'a str, &'static str, String>;
+/// type Either<'a> = <Ephemeral<'a> as Or<Owned<'a>>>::Out;
+/// let either: Either = Owned::from_owned("Hello".into()).relax();
+/// assert_eq!(either.get_owned().map(String::as_str), Some("Hello"));
+/// ```
+pub trait Or<B> {
+    /// The disjunction of `Self` and `B`
+    type Out;
+}
+
+impl<A, MB: Maybe> Or<MB> for An<A> {
+    type Out = An<A>;
+}
+
+impl<A, B> Or<An<B>> for No<A> {
+    type Out = An<B>;
+}
+
+impl<A, B> Or<No<B>> for No<A> {
+    type Out = No<A>;
+}
+
+/// A type-level function that turns [`An`] into [`No`] and vice
+/// versa, keeping the inner type.
+///
+/// An [`Eso`](crate::eso::Eso) applies the function to each of its
+/// type parameters, which gives the variants it cannot have:
+///
+/// ```
+/// # use eso::{maybe::Not, shorthand::t};
+/// type Ephemeral<'a> = t::E<&'a str, &'static str, String>;
+/// type Lasting<'a> = <Ephemeral<'a> as Not>::Out;
+/// assert_eq!(Lasting::SHAPE, (false, true, true));
+/// ```
+pub trait Not {
+    /// The negation of `Self`
+    type Out;
+}
+
+impl<A> Not for An<A> {
+    type Out = No<A>;
+}
+
+impl<A> Not for No<A> {
+    type Out = An<A>;
+}
+
+/// A type-level function that replaces the inner type of every [`No`]
+/// by `()`, keeping [`An`] as it is.
+///
+/// Since a [`No`] never holds a value, its inner type only matters to
+/// the type checker. Two [`Eso`](crate::eso::Eso)s that only differ in
+/// the inner types of their [`No`]s have the same normalized type:
+///
+/// ```
+/// # use eso::{maybe::Normalize, shorthand::t};
+/// type FromString = t::S<&'static str, &'static str, String>;
+/// type FromBox = t::S<&'static str, &'static str, Box<str>>;
+/// type Canonical = t::S<(), &'static str, ()>;
+///
+/// let both: [Canonical; 2] = [
+///     FromString::from_static("Hello").normalize(),
+///     FromBox::from_static("World").normalize(),
+/// ];
+/// let back: FromBox = both[1].clone().relax();
+/// assert_eq!(back.safe_unwrap_static(), "World");
+/// ```
+///
+/// The normalized form can always be [`Relax`]ed back into the original
+/// type. Since [`Unify`](crate::unify::Unify) keeps the inner type of
+/// the first of two [`No`]s, unifying normalized types gives a
+/// normalized type, whatever the order.
+pub trait Normalize: Sized {
+    /// `Self` with the inner type of every [`No`] replaced by `()`
+    type Out: Relax<Self>;
+
+    /// Convert into the normalized form
+    fn normalize(self) -> Self::Out;
+}
+
+impl<A> Normalize for An<A> {
+    type Out = An<A>;
+
+    #[inline]
+    fn normalize(self) -> An<A> {
+        self
+    }
+}
+
+impl<A> Normalize for No<A> {
+    type Out = No<()>;
+
+    fn normalize(self) -> No<()> {
+        self.absurd()
+    }
+}
+
+/// A type-level function to describe the result of borrowing the
+/// inner value of a [`Maybe`], as [`Option::as_ref`] does.
+pub trait MaybeAsRef<'a>: Maybe
+where
+    Self::Inner: 'a,
+{
+    /// An [`An<&'a A>`] for an [`An<A>`], or a [`No<&'a A>`] for a [`No<A>`]
+    type Ref: Maybe<Inner = &'a Self::Inner>;
+
+    /// Borrow the inner value, keeping it wrapped.
+    fn maybe_as_ref(&'a self) -> Self::Ref;
+}
+
+impl<'a, A: 'a> MaybeAsRef<'a> for An<A> {
+    type Ref = An<&'a A>;
+
+    fn maybe_as_ref(&'a self) -> Self::Ref {
+        An(&self.0)
+    }
+}
+
+impl<'a, A: 'a> MaybeAsRef<'a> for No<A> {
+    type Ref = No<&'a A>;
+
+    fn maybe_as_ref(&'a self) -> Self::Ref {
+        self.absurd()
+    }
+}
+
+/// A type-level function to describe the result of mutably borrowing
+/// the inner value of a [`Maybe`], as [`Option::as_mut`] does.
+pub trait MaybeAsMut<'a>: Maybe
+where
+    Self::Inner: 'a,
+{
+    /// An [`An<&'a mut A>`] for an [`An<A>`], or a [`No<&'a mut A>`]
+    /// for a [`No<A>`]
+    type Mut: Maybe<Inner = &'a mut Self::Inner>;
+
+    /// Mutably borrow the inner value, keeping it wrapped.
+    fn maybe_as_mut(&'a mut self) -> Self::Mut;
+}
+
+impl<'a, A: 'a> MaybeAsMut<'a> for An<A> {
+    type Mut = An<&'a mut A>;
+
+    fn maybe_as_mut(&'a mut self) -> Self::Mut {
+        An(&mut self.0)
+    }
+}
+
+impl<'a, A: 'a> MaybeAsMut<'a> for No<A> {
+    type Mut = No<&'a mut A>;
+
+    fn maybe_as_mut(&'a mut self) -> Self::Mut {
+        self.absurd()
+    }
+}
+
+/// A type-level function that drops the [`Maybe`] wrapper, mapping
+/// [`An<A>`] to `A` and [`No<A>`] to the uninhabited [`Infallible`].
+///
+/// Since [`Infallible`] has no values, the compiler knows that code
+/// handling it can never run, and `match` expressions do not need arms
+/// for it.
+pub trait MaybeUnwrap: Maybe {
+    /// `A` for an [`An<A>`], [`Infallible`] for a [`No<A>`]
+    type Value;
+
+    /// Unwrap the inner value
+    fn unwrap_value(self) -> Self::Value;
+}
+
+impl<A> MaybeUnwrap for An<A> {
+    type Value = A;
+
+    fn unwrap_value(self) -> A {
+        self.0
+    }
+}
+
+impl<A> MaybeUnwrap for No<A> {
+    type Value = Infallible;
+
+    fn unwrap_value(self) -> Infallible {
+        self.absurd()
+    }
+}
+
+/// A type-level function to turn a [`Maybe`] of a collection
+/// into a [`Maybe`] of an iterator, see [`MaybeIter`].
+///
+/// The inner type of an [`An`] must be [`IntoIterator`], while
+/// [`No`] accepts any inner type, since it will never be iterated.
+pub trait MaybeIntoIter: Maybe {
+    /// The [`Maybe`] of the iterator
+    type Iter: MaybeIter;
+
+    /// Call [`IntoIterator::into_iter`] on the inner value.
+    fn into_maybe_iter(self) -> Self::Iter;
+}
+
+impl<A: IntoIterator> MaybeIntoIter for An<A> {
+    type Iter = An<A::IntoIter>;
+
+    fn into_maybe_iter(self) -> Self::Iter {
+        An(self.0.into_iter())
+    }
+}
+
+impl<A> MaybeIntoIter for No<A> {
+    type Iter = No<A>;
+
+    fn into_maybe_iter(self) -> Self::Iter {
+        self
+    }
+}
+
+/// A [`Maybe`] of an iterator that yields [`Maybe`]s of
+/// the items.
+pub trait MaybeIter: Maybe {
+    /// An [`An`] of the iterator's item type for an [`An`],
+    /// or a [`No`] for a [`No`]
+    type Item: Maybe;
+
+    /// Advance the inner iterator
+    fn next_item(&mut self) -> Option<Self::Item>;
+
+    /// Forward to [`Iterator::size_hint`] of the inner iterator
+    fn item_size_hint(&self) -> (usize, Option<usize>);
+}
+
+impl<I: Iterator> MaybeIter for An<I> {
+    type Item = An<I::Item>;
+
+    fn next_item(&mut self) -> Option<Self::Item> {
+        self.0.next().map(An)
+    }
+
+    fn item_size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<A> MaybeIter for No<A> {
+    type Item = No<A>;
+
+    fn next_item(&mut self) -> Option<Self::Item> {
+        self.absurd()
+    }
+
+    fn item_size_hint(&self) -> (usize, Option<usize>) {
+        self.absurd()
+    }
+}
+
 impl<A> Clone for No<A> {
     fn clone(&self) -> Self {
         self.absurd()
@@ -282,7 +758,8 @@ impl<A> Unpin for No<A> {}
 /// hold one across a potential panic is dead and will never execute.
 impl<A> UnwindSafe for No<A> {}
 
-/// Safe conversion between [`Maybe`]s.
+/// Safe conversion between [`Maybe`]s, and between types built
+/// from them.
 ///
 /// Casting between [`An`] and [`No`] is safe in the following
 /// combinations:
@@ -292,8 +769,30 @@ impl<A> UnwindSafe for No<A> {}
 ///  - A [`No<A>`] may be cast into any [`An<B>`] or any other
 ///    [`No<B>`], since the [`No<A>`] cannot exist in the
 ///    first place, so the cast can never actually happen.
-pub trait Relax<Into>: Maybe {
-    /// Cast `self` into another type of [`Maybe`].
+///
+/// The conversion extends to types built from [`Maybe`]s:
+/// an [`Eso`](crate::eso::Eso) relaxes each of its type parameters,
+/// and [`Vec`], [`Option`], [`Box`] and tuples relax their contents.
+/// These types are not [`Maybe`]s themselves, so `Relax` does not
+/// require `Self` to be one. This relaxes a whole collection in one
+/// call:
+///
+/// ```
+/// # use eso::{maybe::Relax, shorthand::t};
+/// type Lasting<'a> = t::SO<&'a str, &'static str, String>;
+/// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+///
+/// let lasting = vec![Lasting::from_static("Hello"), Lasting::from_owned("World".into())];
+/// let relaxed: Vec<Str> = lasting.relax();
+/// assert!(relaxed[0].is_static());
+///
+/// let pair: (Option<Lasting>, Box<Lasting>) = (None, Box::new(Lasting::from_static("!")));
+/// let relaxed: (Option<Str>, Box<Str>) = pair.relax();
+/// assert!(relaxed.1.is_static());
+/// ```
+pub trait Relax<Into> {
+    /// Cast `self` into `Into`, which differs from it only in
+    /// [`No`]s being replaced by other [`Maybe`]s.
     ///
     /// See the [trait documentation](Relax) for the rules.
     fn relax(self) -> Into;
@@ -316,3 +815,46 @@ impl<A, B> Relax<No<B>> for No<A> {
         self.absurd()
     }
 }
+
+impl<A: Relax<B>, B> Relax<Vec<B>> for Vec<A> {
+    fn relax(self) -> Vec<B> {
+        self.into_iter().map(Relax::relax).collect()
+    }
+}
+
+impl<A: Relax<B>, B> Relax<Option<B>> for Option<A> {
+    fn relax(self) -> Option<B> {
+        self.map(Relax::relax)
+    }
+}
+
+impl<A: Relax<B>, B> Relax<Box<B>> for Box<A> {
+    fn relax(self) -> Box<B> {
+        Box::new((*self).relax())
+    }
+}
+
+macro_rules! impl_relax_for_tuples {
+    ($( ( $($a:ident $b:ident)+ ) )+) => {
+        $(
+            impl<$($a: Relax<$b>, $b),+> Relax<($($b,)+)> for ($($a,)+) {
+                #[allow(non_snake_case)]
+                fn relax(self) -> ($($b,)+) {
+                    let ($($a,)+) = self;
+                    ($($a.relax(),)+)
+                }
+            }
+        )+
+    };
+}
+
+impl_relax_for_tuples! {
+    (A1 B1)
+    (A1 B1 A2 B2)
+    (A1 B1 A2 B2 A3 B3)
+    (A1 B1 A2 B2 A3 B3 A4 B4)
+    (A1 B1 A2 B2 A3 B3 A4 B4 A5 B5)
+    (A1 B1 A2 B2 A3 B3 A4 B4 A5 B5 A6 B6)
+    (A1 B1 A2 B2 A3 B3 A4 B4 A5 B5 A6 B6 A7 B7)
+    (A1 B1 A2 B2 A3 B3 A4 B4 A5 B5 A6 B6 A7 B7 A8 B8)
+}
@@ -0,0 +1,499 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Ready-made [`Eso`](crate::eso::Eso)-based containers for [`Path`]s and [`OsStr`]s.
+//!
+//! [`EsoPath`] and [`EsoOsStr`] are built on the [`Borrow`](crate::borrow::Borrow)
+//! and [`Take`](crate::borrow::Take) implementations for the standard
+//! library path types.
+//! Their operations are written to keep a reference wherever the
+//! result is identical to the input, and only clone into an owned
+//! [`PathBuf`] or [`OsString`] when the value actually changes.
+
+use std::{
+    borrow::Cow,
+    ffi::{OsStr, OsString},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::shorthand::t;
+
+/// The [`Eso`](crate::eso::Eso) type wrapped by [`EsoPath`]
+pub type PathEso<'a> = t::ESO<&'a Path, &'static Path, PathBuf>;
+
+/// The [`Eso`](crate::eso::Eso) type wrapped by [`EsoOsStr`]
+pub type OsStrEso<'a> = t::ESO<&'a OsStr, &'static OsStr, OsString>;
+
+/// A path that is either borrowed, static or owned.
+///
+/// ```
+/// # use eso::path::EsoPath; use std::path::Path;
+/// let borrowed = EsoPath::from_path(Path::new("src/lib.rs"));
+/// let joined = borrowed.join("");
+/// assert!(joined.as_eso().is_ephemeral());
+///
+/// let joined = joined.join("main.rs");
+/// assert!(joined.as_eso().is_owning());
+/// assert_eq!(&*joined, Path::new("src/lib.rs/main.rs"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EsoPath<'a>(PathEso<'a>);
+
+impl<'a> EsoPath<'a> {
+    /// Create an [`EsoPath`] borrowing the given path
+    pub const fn from_path(p: &'a Path) -> Self {
+        EsoPath(PathEso::from_ref(p))
+    }
+
+    /// Create an [`EsoPath`] from a path that lives forever
+    pub const fn from_static(p: &'static Path) -> Self {
+        EsoPath(PathEso::from_static(p))
+    }
+
+    /// Create an [`EsoPath`] taking ownership of the given path
+    pub const fn from_path_buf(p: PathBuf) -> Self {
+        EsoPath(PathEso::from_owned(p))
+    }
+
+    /// Create an [`EsoPath`] from a [`Cow`], keeping a borrowed
+    /// path borrowed
+    pub fn from_cow(p: Cow<'a, Path>) -> Self {
+        EsoPath(PathEso::from_cow(p))
+    }
+
+    /// Access the underlying [`Eso`](crate::eso::Eso)
+    pub fn as_eso(&self) -> &PathEso<'a> {
+        &self.0
+    }
+
+    /// Unwrap the underlying [`Eso`](crate::eso::Eso)
+    pub fn into_eso(self) -> PathEso<'a> {
+        self.0
+    }
+
+    /// Borrow the contained path
+    pub fn as_path(&self) -> &Path {
+        self.0.get_ref()
+    }
+
+    /// Move the contained path out, cloning it if it is not owned
+    pub fn into_path_buf(self) -> PathBuf {
+        self.0.into_owning().safe_unwrap_owned()
+    }
+
+    /// Convert into an [`EsoOsStr`] without copying
+    pub fn into_os_str(self) -> EsoOsStr<'a> {
+        EsoOsStr(
+            self.0
+                .map(Path::as_os_str, Path::as_os_str, PathBuf::into_os_string),
+        )
+    }
+
+    /// Get a mutable reference to the owned path, cloning a
+    /// referenced path first if necessary
+    pub fn to_mut(&mut self) -> &mut PathBuf {
+        self.0.to_mut()
+    }
+
+    /// Shorten the lifetime of the path, without cloning an
+    /// owned path
+    pub fn narrow<'b>(self) -> EsoPath<'b>
+    where
+        'a: 'b,
+    {
+        self
+    }
+
+    /// Clone a borrowed path, so that the result is no longer tied
+    /// to any lifetime. A static path is kept as it is.
+    pub fn into_static(self) -> EsoPath<'static> {
+        EsoPath(self.0.into_static().relax())
+    }
+
+    /// Clone any referenced path, so that the result is
+    /// definitely owned
+    pub fn into_owning(self) -> EsoPath<'static> {
+        EsoPath(self.0.into_owning().relax())
+    }
+
+    /// Extend the path by `component`, as [`PathBuf::push`] does.
+    ///
+    /// An empty `component` leaves the path as it is, so no clone
+    /// will be made. This differs from [`PathBuf::push`], which
+    /// would append a trailing separator.
+    ///
+    /// ```
+    /// # use eso::path::EsoPath; use std::path::Path;
+    /// let mut path = EsoPath::from_static(Path::new("/usr"));
+    /// path.push("");
+    /// assert!(path.as_eso().is_static());
+    /// path.push("lib");
+    /// assert!(path.as_eso().is_owning());
+    /// assert_eq!(&*path, Path::new("/usr/lib"));
+    /// ```
+    pub fn push<P: AsRef<Path>>(&mut self, component: P) {
+        let component = component.as_ref();
+        if !component.as_os_str().is_empty() {
+            self.to_mut().push(component);
+        }
+    }
+
+    /// Consuming version of [`push`](EsoPath::push), analogous to
+    /// [`Path::join`]
+    pub fn join<P: AsRef<Path>>(mut self, component: P) -> Self {
+        self.push(component);
+        self
+    }
+
+    /// Change the extension, as [`PathBuf::set_extension`] does.
+    ///
+    /// Returns `false` and leaves the path unchanged if there is
+    /// no file name. If the path already has the requested
+    /// extension and ends with its file name, it is left as it is as
+    /// well, but `true` is returned.
+    ///
+    /// ```
+    /// # use eso::path::EsoPath; use std::path::Path;
+    /// let mut path = EsoPath::from_path(Path::new("lib.rs"));
+    /// assert!(path.set_extension("rs"));
+    /// assert!(path.as_eso().is_ephemeral());
+    /// assert!(path.set_extension("txt"));
+    /// assert!(path.as_eso().is_owning());
+    /// assert_eq!(&*path, Path::new("lib.txt"));
+    ///
+    /// // A trailing separator is dropped, as by `PathBuf::set_extension`
+    /// let path = EsoPath::from_path(Path::new("src.rs/")).with_extension("rs");
+    /// assert_eq!(path.as_os_str(), "src.rs");
+    /// ```
+    pub fn set_extension<S: AsRef<OsStr>>(&mut self, extension: S) -> bool {
+        let extension = extension.as_ref();
+        let file_name = match self.file_name() {
+            Some(file_name) => file_name.as_encoded_bytes(),
+            None => return false,
+        };
+        let wanted = if extension.is_empty() {
+            None
+        } else {
+            Some(extension)
+        };
+        let at_end = self.as_os_str().as_encoded_bytes().ends_with(file_name);
+        if !at_end || self.extension() != wanted {
+            self.to_mut().set_extension(extension);
+        }
+        true
+    }
+
+    /// Consuming version of [`set_extension`](EsoPath::set_extension),
+    /// analogous to [`Path::with_extension`]
+    ///
+    /// ```
+    /// # use eso::path::EsoPath; use std::path::Path;
+    /// let path = EsoPath::from_path(Path::new("archive.tar.gz"));
+    /// let path = path.with_extension("gz");
+    /// assert!(path.as_eso().is_ephemeral());
+    /// let path = path.with_extension("");
+    /// assert_eq!(&*path, Path::new("archive.tar"));
+    /// ```
+    pub fn with_extension<S: AsRef<OsStr>>(mut self, extension: S) -> Self {
+        self.set_extension(extension);
+        self
+    }
+
+    /// Normalize the path lexically, i. e. without looking at the
+    /// file system.
+    ///
+    /// This removes `.` components, redundant separators and trailing
+    /// separators, and resolves `..` against a preceding normal component.
+    /// A `..` directly after the root is dropped, while leading `..`
+    /// components of a relative path are kept. A path that ends up
+    /// empty, including the empty path itself, becomes `.`.
+    /// If the path is already in normal form, it is returned unchanged,
+    /// so a borrowed path stays borrowed.
+    ///
+    /// Note that this can change the meaning of a path which
+    /// traverses symbolic links.
+    ///
+    /// ```
+    /// # use eso::path::EsoPath; use std::path::Path;
+    /// let path = EsoPath::from_path(Path::new("src/eso/create.rs")).normalize();
+    /// assert!(path.as_eso().is_ephemeral());
+    ///
+    /// let path = EsoPath::from_path(Path::new("./src//eso/../lib.rs")).normalize();
+    /// assert!(path.as_eso().is_owning());
+    /// assert_eq!(path.as_os_str(), "src/lib.rs");
+    ///
+    /// let path = EsoPath::from_path(Path::new("../a/./b/")).normalize();
+    /// assert_eq!(path.as_os_str(), "../a/b");
+    ///
+    /// let path = EsoPath::from_path(Path::new("")).normalize();
+    /// assert_eq!(path.as_os_str(), ".");
+    /// ```
+    pub fn normalize(self) -> Self {
+        if is_normal(self.as_path()) {
+            return self;
+        }
+        let mut normal = PathBuf::new();
+        for component in self.components() {
+            match component {
+                Component::CurDir => (),
+                Component::ParentDir => match normal.components().next_back() {
+                    Some(Component::Normal(_)) => {
+                        normal.pop();
+                    }
+                    Some(Component::RootDir) | Some(Component::Prefix(_)) => (),
+                    _ => normal.push(Component::ParentDir),
+                },
+                _ => normal.push(component),
+            }
+        }
+        if normal.as_os_str().is_empty() {
+            normal.push(Component::CurDir);
+        }
+        EsoPath::from_path_buf(normal)
+    }
+}
+
+/// Check whether `path` is already in the form that [`EsoPath::normalize`]
+/// produces, without allocating.
+fn is_normal(path: &Path) -> bool {
+    let mut expected_len = 0;
+    let mut previous: Option<Component> = None;
+    let mut components = path.components().peekable();
+    while let Some(component) = components.next() {
+        match component {
+            Component::CurDir if previous.is_some() || components.peek().is_some() => return false,
+            Component::ParentDir => match previous {
+                Some(Component::Normal(_))
+                | Some(Component::RootDir)
+                | Some(Component::Prefix(_)) => return false,
+                _ => (),
+            },
+            _ => (),
+        }
+        match previous {
+            None | Some(Component::RootDir) | Some(Component::Prefix(_)) => (),
+            _ => expected_len += 1,
+        }
+        expected_len += component.as_os_str().len();
+        previous = Some(component);
+    }
+    previous.is_some() && expected_len == path.as_os_str().len()
+}
+
+impl<'a> Deref for EsoPath<'a> {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        self.0.get_ref()
+    }
+}
+
+impl AsRef<Path> for EsoPath<'_> {
+    fn as_ref(&self) -> &Path {
+        self.0.get_ref()
+    }
+}
+
+impl AsRef<OsStr> for EsoPath<'_> {
+    fn as_ref(&self) -> &OsStr {
+        self.as_path().as_os_str()
+    }
+}
+
+impl<'a> From<&'a Path> for EsoPath<'a> {
+    fn from(p: &'a Path) -> Self {
+        Self::from_path(p)
+    }
+}
+
+impl From<PathBuf> for EsoPath<'_> {
+    fn from(p: PathBuf) -> Self {
+        Self::from_path_buf(p)
+    }
+}
+
+impl<'a> From<Cow<'a, Path>> for EsoPath<'a> {
+    fn from(p: Cow<'a, Path>) -> Self {
+        Self::from_cow(p)
+    }
+}
+
+impl<'a> From<EsoOsStr<'a>> for EsoPath<'a> {
+    fn from(s: EsoOsStr<'a>) -> Self {
+        s.into_path()
+    }
+}
+
+impl PartialEq for EsoPath<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_path() == other.as_path()
+    }
+}
+
+impl Eq for EsoPath<'_> {}
+
+impl Hash for EsoPath<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_path().hash(state)
+    }
+}
+
+/// An OS string that is either borrowed, static or owned.
+///
+/// ```
+/// # use eso::path::EsoOsStr; use std::ffi::OsStr;
+/// let mut name = EsoOsStr::from_os_str(OsStr::new("lib"));
+/// name.push("");
+/// assert!(name.as_eso().is_ephemeral());
+/// name.push(".rs");
+/// assert!(name.as_eso().is_owning());
+/// assert_eq!(&*name, "lib.rs");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EsoOsStr<'a>(OsStrEso<'a>);
+
+impl<'a> EsoOsStr<'a> {
+    /// Create an [`EsoOsStr`] borrowing the given string
+    pub const fn from_os_str(s: &'a OsStr) -> Self {
+        EsoOsStr(OsStrEso::from_ref(s))
+    }
+
+    /// Create an [`EsoOsStr`] from a string that lives forever
+    pub const fn from_static(s: &'static OsStr) -> Self {
+        EsoOsStr(OsStrEso::from_static(s))
+    }
+
+    /// Create an [`EsoOsStr`] taking ownership of the given string
+    pub const fn from_os_string(s: OsString) -> Self {
+        EsoOsStr(OsStrEso::from_owned(s))
+    }
+
+    /// Create an [`EsoOsStr`] from a [`Cow`], keeping a borrowed
+    /// string borrowed
+    pub fn from_cow(s: Cow<'a, OsStr>) -> Self {
+        EsoOsStr(OsStrEso::from_cow(s))
+    }
+
+    /// Access the underlying [`Eso`](crate::eso::Eso)
+    pub fn as_eso(&self) -> &OsStrEso<'a> {
+        &self.0
+    }
+
+    /// Unwrap the underlying [`Eso`](crate::eso::Eso)
+    pub fn into_eso(self) -> OsStrEso<'a> {
+        self.0
+    }
+
+    /// Borrow the contained string
+    pub fn as_os_str(&self) -> &OsStr {
+        self.0.get_ref()
+    }
+
+    /// Move the contained string out, cloning it if it is not owned
+    pub fn into_os_string(self) -> OsString {
+        self.0.into_owning().safe_unwrap_owned()
+    }
+
+    /// Convert into an [`EsoPath`] without copying
+    pub fn into_path(self) -> EsoPath<'a> {
+        EsoPath(self.0.map(Path::new, Path::new, PathBuf::from))
+    }
+
+    /// Get a mutable reference to the owned string, cloning a
+    /// referenced string first if necessary
+    pub fn to_mut(&mut self) -> &mut OsString {
+        self.0.to_mut()
+    }
+
+    /// Shorten the lifetime of the string, without cloning an
+    /// owned string
+    pub fn narrow<'b>(self) -> EsoOsStr<'b>
+    where
+        'a: 'b,
+    {
+        self
+    }
+
+    /// Clone a borrowed string, so that the result is no longer tied
+    /// to any lifetime. A static string is kept as it is.
+    pub fn into_static(self) -> EsoOsStr<'static> {
+        EsoOsStr(self.0.into_static().relax())
+    }
+
+    /// Clone any referenced string, so that the result is
+    /// definitely owned
+    pub fn into_owning(self) -> EsoOsStr<'static> {
+        EsoOsStr(self.0.into_owning().relax())
+    }
+
+    /// Append `s`, cloning a referenced string only if `s` is not empty
+    pub fn push<S: AsRef<OsStr>>(&mut self, s: S) {
+        let s = s.as_ref();
+        if !s.is_empty() {
+            self.to_mut().push(s);
+        }
+    }
+}
+
+impl<'a> Deref for EsoOsStr<'a> {
+    type Target = OsStr;
+
+    fn deref(&self) -> &OsStr {
+        self.0.get_ref()
+    }
+}
+
+impl AsRef<OsStr> for EsoOsStr<'_> {
+    fn as_ref(&self) -> &OsStr {
+        self.0.get_ref()
+    }
+}
+
+impl AsRef<Path> for EsoOsStr<'_> {
+    fn as_ref(&self) -> &Path {
+        Path::new(self.as_os_str())
+    }
+}
+
+impl<'a> From<&'a OsStr> for EsoOsStr<'a> {
+    fn from(s: &'a OsStr) -> Self {
+        Self::from_os_str(s)
+    }
+}
+
+impl From<OsString> for EsoOsStr<'_> {
+    fn from(s: OsString) -> Self {
+        Self::from_os_string(s)
+    }
+}
+
+impl<'a> From<Cow<'a, OsStr>> for EsoOsStr<'a> {
+    fn from(s: Cow<'a, OsStr>) -> Self {
+        Self::from_cow(s)
+    }
+}
+
+impl<'a> From<EsoPath<'a>> for EsoOsStr<'a> {
+    fn from(p: EsoPath<'a>) -> Self {
+        p.into_os_str()
+    }
+}
+
+impl PartialEq for EsoOsStr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_os_str() == other.as_os_str()
+    }
+}
+
+impl Eq for EsoOsStr<'_> {}
+
+impl Hash for EsoOsStr<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_os_str().hash(state)
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Proof tokens that carry the shape of an [`Eso`] proven by a match.
+//!
+//! Methods like [`Eso::try_split_static`] return an [`Eso`] whose type
+//! parameters prove the outcome of the match. This proof is lost as
+//! soon as the value is relaxed back into the wider type, e.g. to be
+//! stored in a newtype. The tokens in this module keep the wide type
+//! `T` in their signature, but hold the narrowed [`Eso`], so functions
+//! can require a proven shape and use it without matching again:
+//!
+//! ```
+//! # use eso::{proof::IsLasting, shorthand::t};
+//! type Str<'a> = t::ESO<&'a str, &'static str, String>;
+//!
+//! // The narrowed type cannot hold the reference, so it can be
+//! // relaxed to any lifetime
+//! fn keep(s: IsLasting<Str>) -> Str<'static> {
+//!     s.into_narrowed().relax()
+//! }
+//!
+//! let lasting = IsLasting::prove(Str::from_static("Hello")).unwrap();
+//! assert!(keep(lasting).is_static());
+//! assert!(IsLasting::prove(Str::from_ref("Hello")).is_err());
+//! ```
+
+use std::{fmt, ops::Deref};
+
+use crate::{
+    eso::Eso,
+    maybe::{An, Maybe, Relax},
+    shorthand::x,
+};
+
+/// Names the narrowed forms of an [`Eso`] type that the proof tokens
+/// in this module hold.
+pub trait Shapes {
+    /// The form that is definitely ephemeral, see [`IsEphemeral`]
+    type Ephemeral;
+    /// The form that is definitely static or shared, see [`IsStatic`]
+    type Static;
+    /// The form that is definitely owned, see [`IsOwned`]
+    type Owned;
+    /// The form that is definitely not ephemeral, see [`IsLasting`]
+    type Lasting;
+}
+
+impl<ME: Maybe, MS: Maybe, MO: Maybe> Shapes for Eso<ME, MS, MO> {
+    type Ephemeral = x::E<ME, MS, MO>;
+    type Static = x::S<ME, MS, MO>;
+    type Owned = x::O<ME, MS, MO>;
+    type Lasting = x::so<ME, MS, MO>;
+}
+
+macro_rules! proof_token {
+    (
+        $(#[$attr:meta])*
+        $name:ident => $shape:ident, $rest:ident {
+            $($arm:pat => $res:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        pub struct $name<T: Shapes>(T::$shape);
+
+        impl<T: Shapes> $name<T> {
+            /// Wrap a value that already carries the proof in its type,
+            /// as returned by the `try_split_*` methods of [`Eso`]
+            pub fn from_narrowed(narrowed: T::$shape) -> Self {
+                $name(narrowed)
+            }
+
+            /// Borrow the narrowed [`Eso`]
+            pub fn narrowed(&self) -> &T::$shape {
+                &self.0
+            }
+
+            /// Unwrap the narrowed [`Eso`]
+            pub fn into_narrowed(self) -> T::$shape {
+                self.0
+            }
+
+            /// Forget the proof and relax back into the wide type
+            pub fn into_inner(self) -> T
+            where
+                T::$shape: Relax<T>,
+            {
+                self.0.relax()
+            }
+        }
+
+        impl<ME: Maybe, MS: Maybe, MO: Maybe> $name<Eso<ME, MS, MO>> {
+            /// Match on `eso` and return the proof if successful, or
+            /// the rest with its type parameters narrowed otherwise.
+            pub fn prove(eso: Eso<ME, MS, MO>) -> Result<Self, x::$rest<ME, MS, MO>> {
+                match eso {
+                    $($arm => $res,)+
+                }
+            }
+        }
+
+        impl<T: Shapes> Deref for $name<T> {
+            type Target = T::$shape;
+
+            fn deref(&self) -> &T::$shape {
+                &self.0
+            }
+        }
+
+        impl<T: Shapes> Clone for $name<T>
+        where
+            T::$shape: Clone,
+        {
+            fn clone(&self) -> Self {
+                $name(self.0.clone())
+            }
+        }
+
+        impl<T: Shapes> Copy for $name<T> where T::$shape: Copy {}
+
+        impl<T: Shapes> fmt::Debug for $name<T>
+        where
+            T::$shape: fmt::Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.0).finish()
+            }
+        }
+    };
+}
+
+proof_token! {
+    /// Proof that an [`Eso`] of type `T` is ephemeral.
+    ///
+    /// ```
+    /// # use eso::{proof::IsEphemeral, shorthand::t};
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let proof: IsEphemeral<Str> = IsEphemeral::prove(Str::from_ref("Hello")).unwrap();
+    /// assert_eq!(proof.into_narrowed().safe_unwrap_ephemeral(), "Hello");
+    /// ```
+    IsEphemeral => Ephemeral, so {
+        Eso::E(e) => Ok(IsEphemeral(Eso::E(An(e.unwrap())))),
+        Eso::S(s) => Err(Eso::S(s)),
+        Eso::O(o) => Err(Eso::O(o)),
+    }
+}
+
+proof_token! {
+    /// Proof that an [`Eso`] of type `T` is static or shared.
+    ///
+    /// ```
+    /// # use eso::{proof::IsStatic, shorthand::t};
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let proof: IsStatic<Str> = IsStatic::prove(Str::from_static("Hello")).unwrap();
+    /// assert_eq!(proof.into_narrowed().safe_unwrap_static(), "Hello");
+    /// ```
+    IsStatic => Static, eo {
+        Eso::S(s) => Ok(IsStatic(Eso::S(An(s.unwrap())))),
+        Eso::E(e) => Err(Eso::E(e)),
+        Eso::O(o) => Err(Eso::O(o)),
+    }
+}
+
+proof_token! {
+    /// Proof that an [`Eso`] of type `T` is owned.
+    ///
+    /// ```
+    /// # use eso::{proof::IsOwned, shorthand::t};
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    ///
+    /// fn shout(s: IsOwned<Str>) -> String {
+    ///     s.into_narrowed().safe_unwrap_owned().to_uppercase()
+    /// }
+    ///
+    /// let owned = Str::from_owned("Hello".to_string());
+    /// let proof = IsOwned::from_narrowed(owned.try_split_owned().unwrap());
+    /// assert_eq!(shout(proof), "HELLO");
+    /// ```
+    IsOwned => Owned, es {
+        Eso::O(o) => Ok(IsOwned(Eso::O(An(o.unwrap())))),
+        Eso::E(e) => Err(Eso::E(e)),
+        Eso::S(s) => Err(Eso::S(s)),
+    }
+}
+
+proof_token! {
+    /// Proof that an [`Eso`] of type `T` is lasting, i.e. not ephemeral.
+    ///
+    /// The narrowed [`Eso`] can be used through [`Deref`]:
+    ///
+    /// ```
+    /// # use eso::{proof::IsLasting, shorthand::t};
+    /// type Str<'a> = t::ESO<&'a str, &'static str, String>;
+    /// let proof: IsLasting<Str> = IsLasting::prove(Str::from_owned("Hello".into())).unwrap();
+    /// assert!(proof.is_owning());
+    /// let back: Str = proof.into_inner();
+    /// assert_eq!(back.get_ref::<&str>(), "Hello");
+    /// ```
+    IsLasting => Lasting, E {
+        Eso::E(e) => Err(Eso::E(An(e.unwrap()))),
+        Eso::S(s) => Ok(IsLasting(Eso::S(s))),
+        Eso::O(o) => Ok(IsLasting(Eso::O(o))),
+    }
+}
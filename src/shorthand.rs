@@ -4,7 +4,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-//! Shorthand type aliases to refer to [`Eso`](Eso) with different combinations of
+//! Shorthand type aliases to refer to [`Eso`] with different combinations of
 //! type parameters.
 //!
 //! Import the sub-modules [`t`], [`s`] or [`x`] qualified to use them:
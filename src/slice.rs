@@ -0,0 +1,394 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A ready-made [`Eso`]-based container for slices.
+//!
+//! [`EsoSlice`] holds a borrowed slice, a shared [`Arc<[T]>`](Arc)
+//! or an owned [`Vec`].
+//! Its editing methods check whether the edit would change the
+//! contents, and only clone a referenced slice into a [`Vec`]
+//! via [`to_mut`](EsoSlice::to_mut) if it does.
+
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::{Deref, Index, Range},
+    slice::{Iter, SliceIndex},
+    sync::Arc,
+    vec,
+};
+
+use crate::{eso::Eso, maybe::An, shorthand::t};
+
+/// The [`Eso`] type wrapped by [`EsoSlice`]
+pub type SliceEso<'a, T> = t::ESO<&'a [T], Arc<[T]>, Vec<T>>;
+
+/// The items yielded when iterating over an [`EsoSlice`]
+pub type SliceItem<'a, T> = t::EO<&'a T, T, T>;
+
+/// A slice that is either borrowed, shared or owned.
+///
+/// ```
+/// # use eso::slice::EsoSlice;
+/// let numbers = [1, 2, 3, 4];
+/// let mut slice = EsoSlice::from_slice(&numbers);
+/// slice.set(1, 2);
+/// slice.sort();
+/// slice.retain(|n| *n < 10);
+/// assert!(slice.as_eso().is_ephemeral());
+///
+/// slice.retain(|n| n % 2 == 0);
+/// assert!(slice.as_eso().is_owning());
+/// assert_eq!(&*slice, &[2, 4]);
+/// assert_eq!(slice[1], 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EsoSlice<'a, T>(SliceEso<'a, T>);
+
+impl<'a, T> EsoSlice<'a, T> {
+    /// Create an [`EsoSlice`] borrowing the given slice
+    pub const fn from_slice(s: &'a [T]) -> Self {
+        EsoSlice(Eso::from_ref(s))
+    }
+
+    /// Create an [`EsoSlice`] from a shared slice
+    pub const fn from_shared(s: Arc<[T]>) -> Self {
+        EsoSlice(Eso::from_static(s))
+    }
+
+    /// Create an [`EsoSlice`] taking ownership of the given vector
+    pub const fn from_vec(v: Vec<T>) -> Self {
+        EsoSlice(Eso::from_owned(v))
+    }
+
+    /// Create an [`EsoSlice`] from a [`Cow`], keeping a borrowed
+    /// slice borrowed
+    pub fn from_cow(s: Cow<'a, [T]>) -> Self
+    where
+        T: Clone,
+    {
+        EsoSlice(Eso::from_cow(s))
+    }
+
+    /// Access the underlying [`Eso`]
+    pub fn as_eso(&self) -> &SliceEso<'a, T> {
+        &self.0
+    }
+
+    /// Unwrap the underlying [`Eso`]
+    pub fn into_eso(self) -> SliceEso<'a, T> {
+        self.0
+    }
+
+    /// Borrow the contained slice
+    pub fn as_slice(&self) -> &[T] {
+        self.0.get_ref()
+    }
+
+    /// Move the contained vector out, cloning the elements if
+    /// it is not owned
+    pub fn into_vec(self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.0.into_owning().safe_unwrap_owned()
+    }
+
+    /// Get a mutable reference to the owned vector, cloning a
+    /// referenced slice first if necessary
+    pub fn to_mut(&mut self) -> &mut Vec<T>
+    where
+        T: Clone,
+    {
+        self.0.to_mut()
+    }
+
+    /// Shorten the lifetime of the slice, without cloning an
+    /// owned vector
+    pub fn narrow<'b>(self) -> EsoSlice<'b, T>
+    where
+        'a: 'b,
+    {
+        self
+    }
+
+    /// Clone a borrowed slice, so that the result is no longer tied
+    /// to any lifetime. A shared slice is kept as it is.
+    pub fn into_static(self) -> EsoSlice<'static, T>
+    where
+        T: Clone,
+    {
+        EsoSlice(self.0.into_static().relax())
+    }
+
+    /// Clone any referenced slice, so that the result is
+    /// definitely owned
+    pub fn into_owning(self) -> EsoSlice<'static, T>
+    where
+        T: Clone,
+    {
+        EsoSlice(self.0.into_owning().relax())
+    }
+
+    /// Replace the element at `index` by `value`, unless they
+    /// are already equal.
+    ///
+    /// Returns `true` if the slice was changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: T) -> bool
+    where
+        T: Clone + PartialEq,
+    {
+        if self[index] == value {
+            false
+        } else {
+            self.to_mut()[index] = value;
+            true
+        }
+    }
+
+    /// Append an element to the end of the slice
+    pub fn push(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.to_mut().push(value);
+    }
+
+    /// Append all elements of `other`. An empty `other` leaves
+    /// the slice as it is.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        if !other.is_empty() {
+            self.to_mut().extend_from_slice(other);
+        }
+    }
+
+    /// Shorten the slice to `len` elements. A `len` greater than
+    /// or equal to the current length leaves the slice as it is.
+    ///
+    /// A borrowed slice is shortened without cloning it:
+    ///
+    /// ```
+    /// # use eso::slice::EsoSlice;
+    /// let mut slice = EsoSlice::from_slice(&[1, 2, 3]);
+    /// slice.truncate(2);
+    /// assert!(slice.as_eso().is_ephemeral());
+    /// assert_eq!(&*slice, &[1, 2]);
+    /// ```
+    pub fn truncate(&mut self, len: usize)
+    where
+        T: Clone,
+    {
+        if len >= self.len() {
+            return;
+        }
+        match &mut self.0 {
+            Eso::E(An(s)) => *s = &s[..len],
+            _ => self.to_mut().truncate(len),
+        }
+    }
+
+    /// Keep only the elements for which `f` returns `true`, as
+    /// [`Vec::retain`] does.
+    ///
+    /// `f` is called exactly once per element, in order. The slice
+    /// is only cloned once `f` has rejected an element.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        T: Clone,
+        F: FnMut(&T) -> bool,
+    {
+        let first_rejected = match self.iter().position(|x| !f(x)) {
+            Some(i) => i,
+            None => return,
+        };
+        let mut index = 0;
+        self.to_mut().retain(|x| {
+            let keep = match index.cmp(&first_rejected) {
+                Ordering::Less => true,
+                Ordering::Equal => false,
+                Ordering::Greater => f(x),
+            };
+            index += 1;
+            keep
+        });
+    }
+
+    /// Sort the slice, unless it is sorted already
+    pub fn sort(&mut self)
+    where
+        T: Clone + Ord,
+    {
+        self.sort_by(T::cmp)
+    }
+
+    /// Sort the slice with a comparator function, unless it
+    /// is sorted already
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if self
+            .windows(2)
+            .any(|w| compare(&w[0], &w[1]) == Ordering::Greater)
+        {
+            self.to_mut().sort_by(compare);
+        }
+    }
+
+    /// Sort the slice with a key extraction function, unless it
+    /// is sorted already
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        T: Clone,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Remove consecutive repeated elements, unless there are none
+    pub fn dedup(&mut self)
+    where
+        T: Clone + PartialEq,
+    {
+        if self.windows(2).any(|w| w[0] == w[1]) {
+            self.to_mut().dedup();
+        }
+    }
+}
+
+impl<'a, T> Deref for EsoSlice<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.0.get_ref()
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> Index<I> for EsoSlice<'_, T> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &I::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T> AsRef<[T]> for EsoSlice<'_, T> {
+    fn as_ref(&self) -> &[T] {
+        self.0.get_ref()
+    }
+}
+
+impl<'a, T> From<&'a [T]> for EsoSlice<'a, T> {
+    fn from(s: &'a [T]) -> Self {
+        Self::from_slice(s)
+    }
+}
+
+impl<T> From<Arc<[T]>> for EsoSlice<'_, T> {
+    fn from(s: Arc<[T]>) -> Self {
+        Self::from_shared(s)
+    }
+}
+
+impl<T> From<Vec<T>> for EsoSlice<'_, T> {
+    fn from(v: Vec<T>) -> Self {
+        Self::from_vec(v)
+    }
+}
+
+impl<'a, T: Clone> From<Cow<'a, [T]>> for EsoSlice<'a, T> {
+    fn from(s: Cow<'a, [T]>) -> Self {
+        Self::from_cow(s)
+    }
+}
+
+impl<T: PartialEq> PartialEq for EsoSlice<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for EsoSlice<'_, T> {}
+
+impl<T: Hash> Hash for EsoSlice<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+/// Iterate over the elements, borrowing them from a borrowed slice
+/// and moving them out of an owned vector.
+///
+/// Since the elements of a shared slice can neither be moved out nor
+/// borrowed for `'a`, they are cloned one at a time.
+///
+/// ```
+/// # use eso::slice::EsoSlice;
+/// let words = ["Hello", "World"];
+/// assert!(EsoSlice::from_slice(&words).into_iter().all(|w| w.is_ephemeral()));
+///
+/// let words = vec!["Hello".to_string(), "World".to_string()];
+/// let moved: Vec<String> = EsoSlice::from_vec(words)
+///     .into_iter()
+///     .map(|w| w.try_unwrap_owned().unwrap())
+///     .collect();
+/// assert_eq!(moved, ["Hello", "World"]);
+/// ```
+impl<'a, T: Clone> IntoIterator for EsoSlice<'a, T> {
+    type Item = SliceItem<'a, T>;
+    type IntoIter = IntoIter<'a, T>;
+
+    fn into_iter(self) -> IntoIter<'a, T> {
+        IntoIter(match self.0 {
+            Eso::E(An(s)) => IterState::Borrowed(s.iter()),
+            Eso::S(An(s)) => IterState::Shared(0..s.len(), s),
+            Eso::O(An(v)) => IterState::Owned(v.into_iter()),
+        })
+    }
+}
+
+/// The iterator returned by [`EsoSlice::into_iter`](IntoIterator::into_iter)
+#[derive(Debug, Clone)]
+pub struct IntoIter<'a, T>(IterState<'a, T>);
+
+#[derive(Debug, Clone)]
+enum IterState<'a, T> {
+    Borrowed(Iter<'a, T>),
+    Shared(Range<usize>, Arc<[T]>),
+    Owned(vec::IntoIter<T>),
+}
+
+impl<'a, T: Clone> Iterator for IntoIter<'a, T> {
+    type Item = SliceItem<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            IterState::Borrowed(iter) => iter.next().map(Eso::from_ref),
+            IterState::Shared(range, s) => range.next().map(|i| Eso::from_owned(s[i].clone())),
+            IterState::Owned(iter) => iter.next().map(Eso::from_owned),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.0 {
+            IterState::Borrowed(iter) => iter.size_hint(),
+            IterState::Shared(range, _) => range.size_hint(),
+            IterState::Owned(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for IntoIter<'_, T> {}
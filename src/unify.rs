@@ -18,9 +18,21 @@
 //!
 //! This module also provides the utility trait [`Unify3`] that
 //! contains the ugly type manipulations to apply the [`Unify`] rules
-//! between three types.
+//! between three types, and [`UnifyN`] which applies them to all
+//! elements of a tuple of up to twelve types.
+//!
+//! The [`UnifyVia`] trait opts into a more permissive set of rules,
+//! where two [`An`]s with different inner types unify by converting
+//! both into a given target type with [`Into`]:
+//!
+//! | This ...    | ... unifies via `T` with ... | ... containing ...      | ... producing ... |
+//! |-------------|------------------------------|-------------------------|-------------------|
+//! | [`An<A>`]   | [`An<B>`]                    | `A` and `B` [`Into<T>`] | [`An<T>`]         |
+//! | [`No<A>`]   | [`An<B>`]                    | `B` [`Into<T>`]         | [`An<T>`]         |
+//! | [`An<A>`]   | [`No<B>`]                    | `A` [`Into<T>`]         | [`An<T>`]         |
+//! | [`No<A>`]   | [`No<B>`]                    | any type                | [`No<T>`]         |
 
-use crate::eso::Eso;
+use crate::eso::{Eso, EsoTypes};
 use crate::maybe::{An, Impossible, No};
 
 /// A type `A` that can be unified with another type `B`.
@@ -215,3 +227,283 @@ where
         A::Out::inject_b(c)
     }
 }
+
+/// A type `A` that can be unified with another type `B` by
+/// converting both into the target type `T`.
+///
+/// Unlike [`Unify`], this allows [`An`]s with different inner types
+/// to be unified, as long as both of them are [`Into<T>`]. Since
+/// [`Into`] is reflexive, giving the inner type of one of the sides
+/// as `T` unifies when there is a [`From`] conversion into it.
+///
+/// ```
+/// # use eso::{maybe::*, unify::*};
+/// let a: An<Box<str>> = <An<&str> as UnifyVia<No<String>, Box<str>>>::inject_a(An("Hello"));
+/// let b: An<Box<str>> = <An<&str> as UnifyVia<An<String>, Box<str>>>::inject_b(An("World".into()));
+/// ```
+///
+/// Two [`Eso`]s unify via a target [`Eso`], whose inner types are the
+/// respective targets of the `E`, `S` and `O` type parameters. The
+/// [`An`]s and [`No`]s of the target do not matter:
+///
+/// ```
+/// # use eso::{shorthand::t, unify::*};
+/// # use std::sync::Arc;
+/// type Literal<'a> = t::S<&'a str, &'static str, String>;
+/// type Interned<'a> = t::SO<&'a str, Arc<str>, String>;
+/// type Target<'a> = t::ESO<&'a str, Arc<str>, String>;
+/// type Merged<'a> = t::SO<&'a str, Arc<str>, String>;
+///
+/// let literal: Merged =
+///     <Literal as UnifyVia<Interned, Target>>::inject_a(Literal::from_static("Hello"));
+/// let interned: Merged =
+///     <Literal as UnifyVia<Interned, Target>>::inject_b(Interned::from_static("World".into()));
+/// assert!(literal.is_static() && interned.is_static());
+/// ```
+pub trait UnifyVia<B, T> {
+    /// The result of unifying `Self` and `B` via `T`
+    type Out;
+
+    /// Make an `Out` value, given a value of type `Self`
+    fn inject_a(self) -> Self::Out;
+
+    /// Make an `Out` value, given a value of type `B`
+    fn inject_b(b: B) -> Self::Out;
+}
+
+impl<A: Into<T>, B: Into<T>, T> UnifyVia<An<B>, T> for An<A> {
+    type Out = An<T>;
+
+    fn inject_a(self) -> Self::Out {
+        An(self.0.into())
+    }
+
+    fn inject_b(b: An<B>) -> Self::Out {
+        An(b.0.into())
+    }
+}
+
+impl<A, B: Into<T>, T> UnifyVia<An<B>, T> for No<A> {
+    type Out = An<T>;
+
+    fn inject_a(self) -> Self::Out {
+        self.absurd()
+    }
+
+    fn inject_b(b: An<B>) -> Self::Out {
+        An(b.0.into())
+    }
+}
+
+impl<A: Into<T>, B, T> UnifyVia<No<B>, T> for An<A> {
+    type Out = An<T>;
+
+    fn inject_a(self) -> Self::Out {
+        An(self.0.into())
+    }
+
+    fn inject_b(b: No<B>) -> Self::Out {
+        b.absurd()
+    }
+}
+
+impl<A, B, T> UnifyVia<No<B>, T> for No<A> {
+    type Out = No<T>;
+
+    fn inject_a(self) -> Self::Out {
+        self.absurd()
+    }
+
+    fn inject_b(b: No<B>) -> Self::Out {
+        b.absurd()
+    }
+}
+
+impl<AE, AS, AO, BE, BS, BO, T> UnifyVia<Eso<BE, BS, BO>, T> for Eso<AE, AS, AO>
+where
+    T: EsoTypes,
+    AE: UnifyVia<BE, T::E>,
+    AS: UnifyVia<BS, T::S>,
+    AO: UnifyVia<BO, T::O>,
+{
+    type Out = Eso<AE::Out, AS::Out, AO::Out>;
+
+    fn inject_a(self) -> Self::Out {
+        self.outer_map(AE::inject_a, AS::inject_a, AO::inject_a)
+    }
+
+    fn inject_b(b: Eso<BE, BS, BO>) -> Self::Out {
+        b.outer_map(AE::inject_b, AS::inject_b, AO::inject_b)
+    }
+}
+
+/// Shorthand for unifying three types via `T` by applying
+/// [`UnifyVia`] twice.
+pub trait UnifyVia3<B, C, T> {
+    /// The resulting type when unifying `Self`, `B`, and `C` via `T`
+    type Out3;
+
+    /// Make an `Out3` value, given a value of type `Self`
+    fn inject3_a(self) -> Self::Out3;
+
+    /// Make an `Out3` value, given a value of type `B`
+    fn inject3_b(b: B) -> Self::Out3;
+
+    /// Make an `Out3` value, given a value of type `C`
+    fn inject3_c(c: C) -> Self::Out3;
+}
+
+impl<A, B, C, T> UnifyVia3<B, C, T> for A
+where
+    A: UnifyVia<B, T>,
+    A::Out: UnifyVia<C, T>,
+{
+    type Out3 = <A::Out as UnifyVia<C, T>>::Out;
+
+    fn inject3_a(self) -> Self::Out3 {
+        let ab = A::inject_a(self);
+        A::Out::inject_a(ab)
+    }
+
+    fn inject3_b(b: B) -> Self::Out3 {
+        let ab = A::inject_b(b);
+        A::Out::inject_a(ab)
+    }
+
+    fn inject3_c(c: C) -> Self::Out3 {
+        A::Out::inject_b(c)
+    }
+}
+
+macro_rules! impl_unify_n_injections {
+    ($($name:ident $k:tt),+ $(,)?) => {
+        $(
+            /// Make an `Out` value, given a value of the element type
+            #[doc = concat!("at position ", stringify!($k))]
+            fn $name(part: <Self as InjectN<$k>>::Part) -> Self::Out
+            where
+                Self: InjectN<$k>,
+            {
+                <Self as InjectN<$k>>::inject(part)
+            }
+        )+
+    };
+}
+
+/// Unify all the element types of a tuple by applying [`Unify`]
+/// from left to right.
+///
+/// The element at position `K` is injected into the unified type
+/// with `inject_K`, e.g. to merge the results of the arms of a `match`:
+///
+/// ```
+/// # use eso::{shorthand::t, unify::UnifyN};
+/// type E<'a> = t::E<&'a str, &'static str, String>;
+/// type S<'a> = t::S<&'a str, &'static str, String>;
+/// type O<'a> = t::O<&'a str, &'static str, String>;
+/// type Arms<'a> = (E<'a>, S<'a>, O<'a>, S<'a>);
+///
+/// fn describe(input: &str) -> <Arms<'_> as UnifyN>::Out {
+///     match input {
+///         "" => Arms::inject_1(S::from_static("empty")),
+///         "?" => Arms::inject_3(S::from_static("question")),
+///         _ if input.len() > 10 => Arms::inject_2(O::from_owned(input.to_uppercase())),
+///         _ => Arms::inject_0(E::from_ref(input)),
+///     }
+/// }
+///
+/// let merged: t::ESO<&str, &'static str, String> = describe("Hello");
+/// assert!(merged.is_ephemeral());
+/// assert!(describe("").is_static());
+/// assert!(describe("Hello World!").is_owning());
+/// ```
+pub trait UnifyN {
+    /// The result of unifying all element types
+    type Out;
+
+    impl_unify_n_injections! {
+        inject_0 0, inject_1 1, inject_2 2, inject_3 3,
+        inject_4 4, inject_5 5, inject_6 6, inject_7 7,
+        inject_8 8, inject_9 9, inject_10 10, inject_11 11,
+    }
+}
+
+/// The injection of the element at position `K` of a tuple into
+/// the unified type of [`UnifyN`].
+pub trait InjectN<const K: usize>: UnifyN {
+    /// The type of the element at position `K`
+    type Part;
+
+    /// Make an `Out` value, given the value of the element at position `K`
+    fn inject(part: Self::Part) -> Self::Out;
+}
+
+macro_rules! impl_unify_n {
+    // The one-element tuple unifies to its only element.
+    ($T:ident $k:tt $($rest:tt)*) => {
+        impl<$T> UnifyN for ($T,) {
+            type Out = $T;
+        }
+
+        impl<$T> InjectN<$k> for ($T,) {
+            type Part = $T;
+
+            fn inject(part: $T) -> $T {
+                part
+            }
+        }
+
+        impl_unify_n!(@step [$T $k] $($rest)*);
+    };
+
+    // Unify the tuple made from the prefix and `T` as the prefix
+    // unified with `T`.
+    (@step [$($P:ident $pk:tt)+] $T:ident $k:tt $($rest:tt)*) => {
+        impl<$($P,)+ $T> UnifyN for ($($P,)+ $T,)
+        where
+            ($($P,)+): UnifyN,
+            <($($P,)+) as UnifyN>::Out: Unify<$T>,
+        {
+            type Out = <<($($P,)+) as UnifyN>::Out as Unify<$T>>::Out;
+        }
+
+        impl<$($P,)+ $T> InjectN<$k> for ($($P,)+ $T,)
+        where
+            ($($P,)+): UnifyN,
+            <($($P,)+) as UnifyN>::Out: Unify<$T>,
+        {
+            type Part = $T;
+
+            fn inject(part: $T) -> Self::Out {
+                <<($($P,)+) as UnifyN>::Out as Unify<$T>>::inject_b(part)
+            }
+        }
+
+        impl_unify_n!(@prefix [$($P)+ $T] ($($P,)+) $T; $($P $pk)+);
+        impl_unify_n!(@step [$($P $pk)+ $T $k] $($rest)*);
+    };
+    (@step [$($P:ident $pk:tt)+]) => {};
+
+    // Inject the elements of the prefix by injecting them into the
+    // unified prefix first.
+    (@prefix [$($G:ident)+] $prefix:tt $T:ident; $P:ident $pk:tt $($rest:tt)*) => {
+        impl<$($G),+> InjectN<$pk> for ($($G,)+)
+        where
+            $prefix: InjectN<$pk, Part = $P>,
+            <$prefix as UnifyN>::Out: Unify<$T>,
+        {
+            type Part = $P;
+
+            fn inject(part: $P) -> Self::Out {
+                <<$prefix as UnifyN>::Out as Unify<$T>>::inject_a(
+                    <$prefix as InjectN<$pk>>::inject(part),
+                )
+            }
+        }
+
+        impl_unify_n!(@prefix [$($G)+] $prefix $T; $($rest)*);
+    };
+    (@prefix [$($G:ident)+] $prefix:tt $T:ident;) => {};
+}
+
+impl_unify_n!(T0 0 T1 1 T2 2 T3 3 T4 4 T5 5 T6 6 T7 7 T8 8 T9 9 T10 10 T11 11);
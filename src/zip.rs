@@ -0,0 +1,185 @@
+// Copyright (c) 2021 Sebastien Braun
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Type-level machinery to allow [`Eso::zip`] and [`Eso::zip_ref`]
+//! to work.
+//!
+//! Zipping two [`Eso`]s produces an [`Eso`] of pairs whose variant
+//! is the weaker one of the two inputs:
+//!
+//! | This ...  | ... zipped with ... | ... produces ... | ... converting ...          |
+//! |-----------|---------------------|------------------|-----------------------------|
+//! | `E`       | any                 | `E`              | the other side by [`Borrow`]|
+//! | any       | `E`                 | `E`              | the other side by [`Borrow`]|
+//! | `S`       | `S`                 | `S`              | nothing                     |
+//! | `S`       | `O`                 | `O`              | the `S` side by [`Take`]    |
+//! | `O`       | `S`                 | `O`              | the `S` side by [`Take`]    |
+//! | `O`       | `O`                 | `O`              | nothing                     |
+//!
+//! The type parameters of the result are computed from those of the
+//! inputs with [`MaybeZip`] and [`Unify`], so that a variant of the
+//! result is only [`An`](crate::maybe::An) if one of the rows above
+//! can produce it.
+//!
+//! An `E` result has to borrow from the other side, so only the
+//! borrowing [`ZipRef`] can produce one, at the cost of cloning the
+//! values of the other variants. [`Zip`] consumes two [`Eso`]s that
+//! cannot be ephemeral and moves their values into the result.
+//!
+//! [`Borrow`]: crate::borrow::Borrow
+//! [`Take`]: crate::borrow::Take
+
+use crate::{
+    borrow::Take,
+    eso::{req::MBorrow, Eso},
+    maybe::{Impossible, Maybe, MaybeMap, MaybeZip, No},
+    unify::{Unify, Unify3},
+};
+
+/// Two values that can be zipped together into a value of type
+/// [`Out`](Self::Out), consuming both of them.
+///
+/// ```
+/// # use eso::{shorthand::t, zip::Zip};
+/// type Key = t::SO<&'static str, &'static str, String>;
+/// type Pair = t::SO<(&'static str, &'static str), (&'static str, &'static str), (String, String)>;
+///
+/// let key = Key::from_static("greeting");
+/// let value = Key::from_owned("Hello".to_string());
+/// let pair: Pair = Zip::zip(key, value);
+/// assert_eq!(pair.get_owned(), Some(&("greeting".to_string(), "Hello".to_string())));
+/// ```
+pub trait Zip<B> {
+    /// The result of zipping `Self` and `B`
+    type Out;
+
+    /// Zip `self` and `b` together
+    fn zip(self, b: B) -> Self::Out;
+}
+
+/// Two values that can be zipped together into a value of type
+/// [`Out`](Self::Out), borrowing from both of them for the
+/// lifetime `'a`.
+///
+/// ```
+/// # use eso::{shorthand::t, zip::ZipRef};
+/// type Key<'a> = t::ES<&'a str, &'static str, String>;
+/// type Value<'a> = t::SO<&'a str, &'static str, String>;
+/// type Pair<'a> = t::ESO<(&'a str, &'a str), (&'static str, &'static str), (String, String)>;
+///
+/// let key = Key::from_static("greeting");
+/// let value = Value::from_owned("Hello".to_string());
+/// let pair: Pair = key.zip_ref(&value);
+/// assert_eq!(pair.get_owned(), Some(&("greeting".to_string(), "Hello".to_string())));
+/// ```
+pub trait ZipRef<'a, B> {
+    /// The result of zipping `Self` and `B`
+    type Out;
+
+    /// Zip `self` and `b` together, cloning the values that end up
+    /// in an `S` or `O` result
+    fn zip_ref(&'a self, b: &'a B) -> Self::Out;
+}
+
+/// The pair type of the `E` variant of a zipped [`Eso`]
+type PairE<AE, BE> = (<AE as Maybe>::Inner, <BE as Maybe>::Inner);
+
+/// The `E` parameter of a zipped [`Eso`]
+type ZipE<AE, BE> =
+    <<AE as MaybeMap<PairE<AE, BE>>>::Out as Unify<<BE as MaybeMap<PairE<AE, BE>>>::Out>>::Out;
+
+/// The `S` parameter of a zipped [`Eso`]
+type ZipS<AS, BS> = <AS as MaybeZip<BS>>::Out;
+
+/// A `S` parameter converted to hold the owned type
+type Owned<MS, MO> = <MS as MaybeMap<<MO as Maybe>::Inner>>::Out;
+
+/// The `O` parameter of a zipped [`Eso`]
+type ZipO<AS, AO, BS, BO> = <<AO as MaybeZip<BO>>::Out as Unify3<
+    <Owned<AS, AO> as MaybeZip<BO>>::Out,
+    <AO as MaybeZip<Owned<BS, BO>>>::Out,
+>>::Out3;
+
+impl<AE, AS, AO, BE, BS, BO> Zip<Eso<No<BE>, BS, BO>> for Eso<No<AE>, AS, AO>
+where
+    AS: MaybeMap<AO::Inner> + MaybeZip<BS>,
+    BS: MaybeMap<BO::Inner>,
+    AS::Inner: Take<AO::Inner>,
+    BS::Inner: Take<BO::Inner>,
+    AO: MaybeZip<BO> + MaybeZip<Owned<BS, BO>>,
+    BO: Maybe,
+    Owned<AS, AO>: MaybeZip<BO>,
+    <AO as MaybeZip<BO>>::Out:
+        Unify3<<Owned<AS, AO> as MaybeZip<BO>>::Out, <AO as MaybeZip<Owned<BS, BO>>>::Out>,
+{
+    type Out = Eso<No<(AE, BE)>, ZipS<AS, BS>, ZipO<AS, AO, BS, BO>>;
+
+    fn zip(self, b: Eso<No<BE>, BS, BO>) -> Self::Out {
+        match (self, b) {
+            (Eso::E(a), _) => a.absurd(),
+            (_, Eso::E(b)) => b.absurd(),
+            (Eso::S(a), Eso::S(b)) => Eso::S(a.do_zip(b)),
+            (Eso::S(a), Eso::O(b)) => Eso::O(<AO as MaybeZip<BO>>::Out::inject3_b(
+                a.map(Take::own).do_zip(b),
+            )),
+            (Eso::O(a), Eso::S(b)) => Eso::O(<AO as MaybeZip<BO>>::Out::inject3_c(
+                a.do_zip(b.map(Take::own)),
+            )),
+            (Eso::O(a), Eso::O(b)) => Eso::O(<AO as MaybeZip<BO>>::Out::inject3_a(a.do_zip(b))),
+        }
+    }
+}
+
+impl<'a, AE, AS, AO, BE, BS, BO> ZipRef<'a, Eso<BE, BS, BO>> for Eso<AE, AS, AO>
+where
+    AE: MaybeMap<PairE<AE, BE>> + Clone,
+    BE: MaybeMap<PairE<AE, BE>> + Clone,
+    AE::Out: Unify<BE::Out>,
+    AE::Inner: 'a,
+    BE::Inner: 'a,
+    AS: MBorrow<'a, AE::Inner> + MaybeMap<AO::Inner> + MaybeZip<BS> + Clone,
+    BS: MBorrow<'a, BE::Inner> + MaybeMap<BO::Inner> + Clone,
+    AS::Inner: Take<AO::Inner>,
+    BS::Inner: Take<BO::Inner>,
+    AO: MBorrow<'a, AE::Inner> + MaybeZip<BO> + MaybeZip<Owned<BS, BO>> + Clone,
+    BO: MBorrow<'a, BE::Inner> + Clone,
+    Owned<AS, AO>: MaybeZip<BO>,
+    <AO as MaybeZip<BO>>::Out:
+        Unify3<<Owned<AS, AO> as MaybeZip<BO>>::Out, <AO as MaybeZip<Owned<BS, BO>>>::Out>,
+{
+    type Out = Eso<ZipE<AE, BE>, ZipS<AS, BS>, ZipO<AS, AO, BS, BO>>;
+
+    fn zip_ref(&'a self, b: &'a Eso<BE, BS, BO>) -> Self::Out {
+        match (self, b) {
+            (Eso::E(a), b) => {
+                let b = match b {
+                    Eso::E(b) => b.clone().unwrap(),
+                    Eso::S(b) => b.borrow(),
+                    Eso::O(b) => b.borrow(),
+                };
+                Eso::E(AE::Out::inject_a(a.clone().map(|a| (a, b))))
+            }
+            (a, Eso::E(b)) => {
+                let a = match a {
+                    Eso::E(a) => a.clone().unwrap(),
+                    Eso::S(a) => a.borrow(),
+                    Eso::O(a) => a.borrow(),
+                };
+                Eso::E(AE::Out::inject_b(b.clone().map(|b| (a, b))))
+            }
+            (Eso::S(a), Eso::S(b)) => Eso::S(a.clone().do_zip(b.clone())),
+            (Eso::S(a), Eso::O(b)) => Eso::O(<AO as MaybeZip<BO>>::Out::inject3_b(
+                a.clone().map(Take::own).do_zip(b.clone()),
+            )),
+            (Eso::O(a), Eso::S(b)) => Eso::O(<AO as MaybeZip<BO>>::Out::inject3_c(
+                a.clone().do_zip(b.clone().map(Take::own)),
+            )),
+            (Eso::O(a), Eso::O(b)) => Eso::O(<AO as MaybeZip<BO>>::Out::inject3_a(
+                a.clone().do_zip(b.clone()),
+            )),
+        }
+    }
+}
@@ -11,7 +11,7 @@ use crate::utils::amend_env_var;
 
 mod utils;
 
-const XTASK_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+const XTASK_DIR: &str = env!("CARGO_MANIFEST_DIR");
 
 lazy_static! {
     static ref WORKSPACE_ROOT: PathBuf = {